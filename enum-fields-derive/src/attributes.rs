@@ -0,0 +1,698 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! Parsing of the `#[enum_fields(...)]` helper attributes.
+
+use proc_macro2::{Span, TokenTree};
+use syn::ext::IdentExt;
+use syn::meta::ParseNestedMeta;
+use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
+
+use crate::case::VariantCase;
+
+/// The name of the helper attribute, as in `#[enum_fields(...)]`.
+const ATTRIBUTE_NAME: &str = "enum_fields";
+
+/// The options that can be specified on the enum itself.
+#[derive(Default)]
+pub(crate) struct EnumAttributes {
+    /// Return an `Option` from every getter, even for fields present in every
+    /// variant: `#[enum_fields(always_optional)]`
+    pub(crate) always_optional: bool,
+
+    /// Implement `AsRef` for the only field present in every variant:
+    /// `#[enum_fields(as_ref)]`
+    pub(crate) as_ref: bool,
+
+    /// Generate `as_<variant>()` getters returning all fields of a variant:
+    /// `#[enum_fields(as_variant)]`
+    pub(crate) as_variant: bool,
+
+    /// Hide the generated methods from the documentation:
+    /// `#[enum_fields(doc_hidden)]`
+    pub(crate) doc_hidden: bool,
+
+    /// Generate `same_<field>()` methods comparing the field of two enums:
+    /// `#[enum_fields(compare)]`
+    pub(crate) compare: bool,
+
+    /// Make the getters `const fn`s: `#[enum_fields(const_getters)]`
+    pub(crate) const_getters: bool,
+
+    /// Return an `Option<&T>` for every `Option<T>` field that isn't present in
+    /// every variant: `#[enum_fields(flatten_option)]`
+    pub(crate) flatten_option: bool,
+
+    /// Implement the companion trait for `Box<Self>` as well:
+    /// `#[enum_fields(impl_for_box)]`
+    pub(crate) impl_for_box: bool,
+
+    /// Use the visibility of the enum for the generated methods, instead of
+    /// `pub`: `#[enum_fields(inherit_vis)]`
+    pub(crate) inherit_vis: bool,
+
+    /// Mark the generated methods `#[inline(always)]` instead of `#[inline]`:
+    /// `#[enum_fields(inline(always))]`
+    pub(crate) inline_always: bool,
+
+    /// Generate consuming `into_<field>()` getters: `#[enum_fields(into)]`
+    pub(crate) into: bool,
+
+    /// Generate `<field>_iter()` getters returning an iterator over the
+    /// elements of `Vec` fields: `#[enum_fields(iter)]`
+    pub(crate) iter: bool,
+
+    /// Generate `map_<field>()` methods applying a function to the field:
+    /// `#[enum_fields(map)]`
+    pub(crate) map: bool,
+
+    /// Mark the immutable getters `#[must_use]`: `#[enum_fields(must_use)]`
+    pub(crate) must_use: bool,
+
+    /// Generate `<field>_or()` getters returning a default for variants
+    /// without the field: `#[enum_fields(or)]`
+    pub(crate) or: bool,
+
+    /// Generate `<field>_path()` getters returning a `&Path` for `PathBuf`
+    /// fields: `#[enum_fields(path_accessors)]`
+    pub(crate) path_accessors: bool,
+
+    /// Don't generate any mutable getters, setters or builder methods:
+    /// `#[enum_fields(readonly)]`
+    pub(crate) readonly: bool,
+
+    /// Generate `set_<field>()` setters: `#[enum_fields(setters)]`
+    pub(crate) setters: bool,
+
+    /// Generate a `shared_key()` method returning the fields present in every
+    /// variant: `#[enum_fields(shared_key)]`
+    pub(crate) shared_key: bool,
+
+    /// Generate a `fields_mut()` method returning the mutable fields present
+    /// in every variant at once: `#[enum_fields(split_mut)]`
+    pub(crate) split_mut: bool,
+
+    /// Generate `<field>_str()` getters returning a `&str` for `String`
+    /// fields: `#[enum_fields(str_accessors)]`
+    pub(crate) str_accessors: bool,
+
+    /// Generate `replace_<field>(value)` methods returning the previous value:
+    /// `#[enum_fields(replace)]`
+    pub(crate) replace: bool,
+
+    /// Generate `swap_<field>(other)` methods swapping the field with the one
+    /// of another enum: `#[enum_fields(swap)]`
+    pub(crate) swap: bool,
+
+    /// Generate `take_<field>()` methods replacing the field with its default:
+    /// `#[enum_fields(take)]`
+    pub(crate) take: bool,
+
+    /// Implement `Deref` for enums whose variants all wrap the same single
+    /// field: `#[enum_fields(transparent)]`
+    pub(crate) transparent: bool,
+
+    /// Generate `try_<field>()` getters returning a `Result` for fields that
+    /// aren't present in every variant: `#[enum_fields(try)]`
+    pub(crate) try_getters: bool,
+
+    /// Generate `unsafe` getters for the fields of a `union`:
+    /// `#[enum_fields(unsafe_getters)]`
+    pub(crate) unsafe_getters: bool,
+
+    /// Generate `unwrap_<field>()` getters panicking for variants without the
+    /// field: `#[enum_fields(unwrap)]`
+    pub(crate) unwrap: bool,
+
+    /// Generate `for_each_<field>()` methods calling a function with the field:
+    /// `#[enum_fields(visit)]`
+    pub(crate) visit: bool,
+
+    /// Generate builder-style `with_<field>()` methods:
+    /// `#[enum_fields(with)]`
+    pub(crate) with: bool,
+
+    /// The visibility of the generated methods, which is `pub` by default:
+    /// `#[enum_fields(vis = "pub(crate)")]`
+    pub(crate) vis: Option<syn::Visibility>,
+
+    /// How the names of the variants are converted for the names of their
+    /// methods, which is `snake_case` by default: `#[enum_fields(case = "verbatim")]`
+    pub(crate) case: VariantCase,
+
+    /// The prefix of the names of the getters and mutable getters:
+    /// `#[enum_fields(getter_prefix = "get_")]`
+    pub(crate) getter_prefix: Option<syn::LitStr>,
+
+    /// The suffix of the names of the getters, which is empty by default:
+    /// `#[enum_fields(getter_suffix = "_ref")]`
+    pub(crate) getter_suffix: Option<syn::LitStr>,
+
+    /// The suffix of the names of the mutable getters, which is `_mut` by
+    /// default: `#[enum_fields(mut_suffix = "_mut_ref")]`
+    pub(crate) mut_suffix: Option<syn::LitStr>,
+
+    /// The error type of the `try_<field>()` getters instead of
+    /// `EnumFieldError`, which it has to be convertible from:
+    /// `#[enum_fields(getter_result = "MyError")]`
+    pub(crate) getter_result: Option<syn::Type>,
+
+    /// Generate an `eq_by_<field>()` method comparing two enums by the field:
+    /// `#[enum_fields(eq_field = "id")]`
+    pub(crate) eq_field: Option<syn::Ident>,
+
+    /// Generate a trait with the getters, implemented by the enum:
+    /// `#[enum_fields(trait_name = "EntityFields")]`
+    pub(crate) trait_name: Option<syn::Ident>,
+
+    /// The only fields to generate methods for, ignoring any other field:
+    /// `#[enum_fields(only(name, id))]`
+    pub(crate) only: Option<Vec<syn::Ident>>,
+
+    /// The predicates to add to the where-clause of the generated `impl`:
+    /// `#[enum_fields(bound = "T: Clone + Debug")]`
+    pub(crate) bound: Vec<syn::WherePredicate>,
+
+    /// The lints to allow on every generated method:
+    /// `#[enum_fields(allow = "clippy::must_use_candidate")]`
+    pub(crate) allow: Vec<syn::Path>,
+}
+
+impl EnumAttributes {
+    pub(crate) fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+
+        parse_nested_metas(attrs, |meta| {
+            if meta.path.is_ident("always_optional") {
+                result.always_optional = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("as_ref") {
+                result.as_ref = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("as_variant") {
+                result.as_variant = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("compare") {
+                result.compare = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("const_getters") {
+                result.const_getters = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("doc_hidden") {
+                result.doc_hidden = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("flatten_option") {
+                result.flatten_option = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("impl_for_box") {
+                result.impl_for_box = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("inherit_vis") {
+                result.inherit_vis = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("inline") {
+                return meta.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("always") {
+                        result.inline_always = true;
+                        return Ok(());
+                    }
+
+                    Err(meta.error("unsupported `inline` option, only `always` is supported"))
+                });
+            }
+
+            if meta.path.is_ident("into") {
+                result.into = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("iter") {
+                result.iter = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("map") {
+                result.map = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("must_use") {
+                result.must_use = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("or") {
+                result.or = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("path_accessors") {
+                result.path_accessors = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("readonly") {
+                result.readonly = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("setters") {
+                result.setters = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("shared_key") {
+                result.shared_key = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("split_mut") {
+                result.split_mut = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("str_accessors") {
+                result.str_accessors = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("replace") {
+                result.replace = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("swap") {
+                result.swap = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("take") {
+                result.take = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("transparent") {
+                result.transparent = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("try") {
+                result.try_getters = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("unsafe_getters") {
+                result.unsafe_getters = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("unwrap") {
+                result.unwrap = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("visit") {
+                result.visit = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("with") {
+                result.with = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("vis") {
+                result.vis = Some(parse_string_value(&meta)?);
+                return Ok(());
+            }
+
+            if meta.path.is_ident("case") {
+                result.case = VariantCase::parse(&meta.value()?.parse()?)?;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("getter_prefix") {
+                result.getter_prefix = Some(meta.value()?.parse()?);
+                return Ok(());
+            }
+
+            if meta.path.is_ident("getter_suffix") {
+                result.getter_suffix = Some(meta.value()?.parse()?);
+                return Ok(());
+            }
+
+            if meta.path.is_ident("mut_suffix") {
+                let suffix: syn::LitStr = meta.value()?.parse()?;
+                if suffix.value().is_empty() {
+                    return Err(syn::Error::new(
+                        suffix.span(),
+                        "`mut_suffix` can't be empty, as the mutable getters would have the same names as the getters",
+                    ));
+                }
+
+                result.mut_suffix = Some(suffix);
+                return Ok(());
+            }
+
+            if meta.path.is_ident("getter_result") {
+                result.getter_result = Some(parse_string_value(&meta)?);
+                return Ok(());
+            }
+
+            if meta.path.is_ident("eq_field") {
+                result.eq_field = Some(parse_string_value(&meta)?);
+                return Ok(());
+            }
+
+            if meta.path.is_ident("trait_name") {
+                result.trait_name = Some(parse_string_value(&meta)?);
+                return Ok(());
+            }
+
+            if meta.path.is_ident("only") {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let fields = content.parse_terminated(syn::Ident::parse_any, syn::Token![,])?;
+                result.only.get_or_insert_with(Vec::new).extend(fields);
+                return Ok(());
+            }
+
+            if meta.path.is_ident("bound") {
+                let bound: syn::LitStr = meta.value()?.parse()?;
+                let predicates = bound.parse_with(Punctuated::<syn::WherePredicate, syn::Token![,]>::parse_terminated)?;
+                result.bound.extend(predicates);
+                return Ok(());
+            }
+
+            if meta.path.is_ident("allow") {
+                let lints: syn::LitStr = meta.value()?.parse()?;
+                let lints = lints.parse_with(Punctuated::<syn::Path, syn::Token![,]>::parse_terminated)?;
+                result.allow.extend(lints);
+                return Ok(());
+            }
+
+            Err(meta.error("unsupported `enum_fields` enum attribute"))
+        })?;
+
+        if let Some(getter_suffix) = &result.getter_suffix {
+            let mut_suffix = result.mut_suffix.as_ref().map_or_else(|| "_mut".to_string(), syn::LitStr::value);
+            if getter_suffix.value() == mut_suffix {
+                return Err(syn::Error::new(getter_suffix.span(), format!(
+                    "`getter_suffix` can't be `{mut_suffix}`, as the getters would have the same names as the mutable getters"
+                )));
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// The options that can be specified on a variant of the enum.
+#[derive(Default)]
+pub(crate) struct VariantAttributes {
+    /// Exclude this variant from sharing fields, as if it didn't have any:
+    /// `#[enum_fields(ignore)]`
+    pub(crate) ignore: bool,
+}
+
+impl VariantAttributes {
+    pub(crate) fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+
+        parse_nested_metas(attrs, |meta| {
+            if meta.path.is_ident("ignore") {
+                result.ignore = true;
+                return Ok(());
+            }
+
+            Err(meta.error("unsupported `enum_fields` variant attribute"))
+        })?;
+
+        Ok(result)
+    }
+}
+
+/// The options that can be specified on a field of a variant.
+#[derive(Default)]
+pub(crate) struct FieldAttributes {
+    /// Exclude this field from getter generation: `#[enum_fields(skip)]`
+    pub(crate) skip: bool,
+
+    /// Implement `AsRef` for this field, which must be present in every
+    /// variant: `#[enum_fields(as_ref)]`
+    pub(crate) as_ref: bool,
+
+    /// Return the field by value instead of by reference:
+    /// `#[enum_fields(by_value)]`
+    pub(crate) by_value: bool,
+
+    /// Generate a `<field>_cloned()` getter returning a clone of the field:
+    /// `#[enum_fields(clone)]`
+    pub(crate) clone: bool,
+
+    /// Return an `Option<&T>` for an `Option<T>` field, instead of wrapping it
+    /// in another `Option`: `#[enum_fields(flatten_option)]`
+    pub(crate) flatten_option: bool,
+
+    /// Return the target of a `Box<T>`, `Rc<T>`, `Arc<T>` or `Cow<'_, T>` field
+    /// instead of the pointer itself: `#[enum_fields(deref)]`
+    pub(crate) deref: bool,
+
+    /// Return an `Option<&mut T>` from the mutable getter of an `Option<T>`
+    /// field, instead of an `&mut Option<T>`: `#[enum_fields(option_mut_inner)]`
+    pub(crate) option_mut_inner: bool,
+
+    /// Generate an `into_<field>_opt()` method converting the variants holding
+    /// nothing but this field into it: `#[enum_fields(from)]`
+    pub(crate) from: bool,
+
+    /// Generate `<field>_ptr()` and `<field>_ptr_mut()` getters returning raw
+    /// pointers to the field, which are null for variants without it:
+    /// `#[enum_fields(raw_ptr)]`
+    pub(crate) raw_ptr: bool,
+
+    /// Generate an `unsafe` mutable getter projecting a pinned enum onto the
+    /// field, asserting it is structurally pinned: `#[enum_fields(pin)]`
+    pub(crate) pin: bool,
+
+    /// Don't generate a mutable getter, setter or builder method for this field:
+    /// `#[enum_fields(readonly)]`
+    pub(crate) readonly: bool,
+
+    /// Don't generate a mutable getter for this field in this variant, while
+    /// still generating its setters: `#[enum_fields(no_mut)]`
+    pub(crate) no_mut: bool,
+
+    /// The constant to return from the getter for variants without the field,
+    /// instead of returning an `Option`: `#[enum_fields(default = "0")]`
+    pub(crate) default: Option<syn::Expr>,
+
+    /// Return a clone of the field from the getter, or `T::default()` for
+    /// variants without it, instead of returning an `Option`:
+    /// `#[enum_fields(or_default)]`
+    pub(crate) or_default: bool,
+
+    /// The type to return a reference to from the getters, to which a
+    /// reference to the field coerces: `#[enum_fields(ty = "[u8]")]`
+    pub(crate) ty: Option<syn::Type>,
+
+    /// The documentation of the getter and mutable getter of this field,
+    /// instead of the default one: `#[enum_fields(getter_doc = "...")]`
+    pub(crate) getter_doc: Option<syn::LitStr>,
+
+    /// Use a different name for the getters of this field:
+    /// `#[enum_fields(rename = "...")]`
+    pub(crate) rename: Option<syn::Ident>,
+
+    /// The visibility of the methods generated for this field, overriding the
+    /// one of the enum: `#[enum_fields(vis = "pub(crate)")]`
+    pub(crate) vis: Option<syn::Visibility>,
+
+    /// The getters of the type of this field to generate on the enum as well,
+    /// along with their return types: `#[enum_fields(delegate(name: &str))]`
+    pub(crate) delegate: Vec<DelegatedGetter>,
+}
+
+/// A getter of the type of a field, which the enum delegates to.
+pub(crate) struct DelegatedGetter {
+    pub(crate) ident: syn::Ident,
+
+    pub(crate) ty: syn::Type,
+}
+
+impl DelegatedGetter {
+    /// Parses a single `name: Type` item of `delegate(...)`.
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = input.parse()?;
+        input.parse::<syn::Token![:]>()?;
+        let ty = input.parse()?;
+        Ok(Self { ident, ty })
+    }
+}
+
+impl FieldAttributes {
+    pub(crate) fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+
+        parse_nested_metas(attrs, |meta| {
+            if meta.path.is_ident("skip") {
+                result.skip = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("as_ref") {
+                result.as_ref = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("by_value") {
+                result.by_value = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("clone") {
+                result.clone = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("flatten_option") {
+                result.flatten_option = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("deref") {
+                result.deref = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("option_mut_inner") {
+                result.option_mut_inner = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("from") {
+                result.from = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("raw_ptr") {
+                result.raw_ptr = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("pin") {
+                result.pin = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("readonly") {
+                result.readonly = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("no_mut") {
+                result.no_mut = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("default") {
+                result.default = Some(parse_string_value(&meta)?);
+                return Ok(());
+            }
+
+            if meta.path.is_ident("or_default") {
+                result.or_default = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("ty") {
+                result.ty = Some(parse_string_value(&meta)?);
+                return Ok(());
+            }
+
+            if meta.path.is_ident("getter_doc") {
+                result.getter_doc = Some(meta.value()?.parse()?);
+                return Ok(());
+            }
+
+            if meta.path.is_ident("rename") {
+                let name: syn::LitStr = meta.value()?.parse()?;
+                result.rename = Some(name.parse().map_err(|_| {
+                    syn::Error::new(name.span(), "`rename` must be a valid identifier")
+                })?);
+                return Ok(());
+            }
+
+            if meta.path.is_ident("vis") {
+                result.vis = Some(parse_string_value(&meta)?);
+                return Ok(());
+            }
+
+            if meta.path.is_ident("delegate") {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let getters = content.parse_terminated(DelegatedGetter::parse, syn::Token![,])?;
+                result.delegate.extend(getters);
+                return Ok(());
+            }
+
+            Err(meta.error("unsupported `enum_fields` field attribute"))
+        })?;
+
+        Ok(result)
+    }
+}
+
+/// Calls `parser` for every item inside the `#[enum_fields(...)]` attributes,
+/// ignoring any other attribute.
+fn parse_nested_metas(
+    attrs: &[syn::Attribute],
+    mut parser: impl FnMut(ParseNestedMeta) -> syn::Result<()>,
+) -> syn::Result<()> {
+    for attr in attrs {
+        if attr.path().is_ident(ATTRIBUTE_NAME) {
+            attr.parse_nested_meta(&mut parser)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Finds the span of the option `name` inside the `#[enum_fields(...)]`
+/// attributes, to point at it in diagnostics about options that don't apply.
+pub(crate) fn option_span(attrs: &[syn::Attribute], name: &str) -> Option<Span> {
+    attrs.iter()
+        .filter(|attr| attr.path().is_ident(ATTRIBUTE_NAME))
+        .filter_map(|attr| attr.meta.require_list().ok())
+        .flat_map(|list| list.tokens.clone())
+        .find_map(|token| match token {
+            TokenTree::Ident(ident) if ident == name => Some(ident.span()),
+            _ => None,
+        })
+}
+
+/// Parses the string literal of a `key = "..."` item, e.g. a visibility in
+/// `vis = "pub(crate)"`.
+fn parse_string_value<T: syn::parse::Parse>(meta: &ParseNestedMeta) -> syn::Result<T> {
+    let value: syn::LitStr = meta.value()?.parse()?;
+    value.parse()
+}
@@ -0,0 +1,87 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! Conversion of identifiers between naming conventions.
+
+/// How the names of variants are converted for the names of the methods
+/// generated for them, e.g. `is_company_name()`: `#[enum_fields(case = "...")]`
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum VariantCase {
+    /// `CompanyName` becomes `company_name`.
+    #[default]
+    Snake,
+
+    /// `CompanyName` becomes `companyName`.
+    Camel,
+
+    /// `CompanyName` stays `CompanyName`.
+    Verbatim,
+}
+
+impl VariantCase {
+    /// Parses the value of `case = "..."`.
+    pub(crate) fn parse(value: &syn::LitStr) -> syn::Result<Self> {
+        match value.value().as_str() {
+            "snake" => Ok(Self::Snake),
+            "camel" => Ok(Self::Camel),
+            "verbatim" => Ok(Self::Verbatim),
+            _ => Err(syn::Error::new(value.span(), "unsupported `case`, expected `snake`, `camel` or `verbatim`")),
+        }
+    }
+
+    /// Converts the name of a variant to this case.
+    pub(crate) fn convert(self, ident: &str) -> String {
+        match self {
+            Self::Snake => to_snake_case(ident),
+            Self::Camel => to_lower_camel_case(ident),
+            Self::Verbatim => ident.to_string(),
+        }
+    }
+}
+
+/// Converts an `UpperCamelCase` identifier (e.g. a variant name) to
+/// `snake_case`, keeping acronyms together: `HTTPServer` becomes
+/// `http_server`.
+pub(crate) fn to_snake_case(ident: &str) -> String {
+    let chars: Vec<char> = ident.chars().collect();
+    let mut result = String::with_capacity(ident.len() + 4);
+
+    for (index, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && index != 0 {
+            let previous = chars[index - 1];
+            let next_is_lowercase = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+
+            if previous != '_' && (previous.is_lowercase() || previous.is_ascii_digit()
+                    || (previous.is_uppercase() && next_is_lowercase)) {
+                result.push('_');
+            }
+        }
+
+        result.extend(c.to_lowercase());
+    }
+
+    result
+}
+
+/// Converts an `UpperCamelCase` identifier (e.g. a variant name) to
+/// `lowerCamelCase`, lowercasing a leading acronym: `HTTPServer` becomes
+/// `httpServer`.
+pub(crate) fn to_lower_camel_case(ident: &str) -> String {
+    let chars: Vec<char> = ident.chars().collect();
+    let mut result = String::with_capacity(ident.len());
+
+    for (index, &c) in chars.iter().enumerate() {
+        let leading = chars[..=index].iter().all(|c| c.is_uppercase());
+        let next_is_lowercase = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+
+        // The last letter of a leading acronym starts the next word, unless
+        // it is the first letter.
+        if leading && (index == 0 || !next_is_lowercase) {
+            result.extend(c.to_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
@@ -0,0 +1,2799 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The derive macro of the [`enum-fields`](https://docs.rs/enum-fields) crate,
+//! which should be used through that crate instead.
+
+mod attributes;
+mod case;
+#[cfg(test)]
+mod tests;
+
+use std::collections::HashMap;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Group, Ident, Span, TokenTree};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::ext::IdentExt;
+use syn::spanned::Spanned;
+
+use crate::attributes::{option_span, EnumAttributes, FieldAttributes, VariantAttributes};
+use crate::case::VariantCase;
+
+#[proc_macro_derive(EnumFields, attributes(enum_fields))]
+pub fn enum_fields_macro_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).unwrap();
+    impl_for_input(&ast)
+}
+
+#[proc_macro_attribute]
+pub fn enum_fields_delegate(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let ast = syn::parse_macro_input!(item as syn::DeriveInput);
+    let delegate = syn::parse_macro_input!(attr as DelegateTarget);
+
+    let getters = impl_for_wrapper(&ast, &delegate)
+        .unwrap_or_else(syn::Error::into_compile_error);
+
+    quote! {
+        #ast
+        #getters
+    }.into()
+}
+
+/// The arguments of `#[enum_fields_delegate(Entity, 0)]`: the enum, and the
+/// field of the wrapper containing it.
+struct DelegateTarget {
+    enum_ident: Ident,
+
+    member: syn::Index,
+}
+
+impl syn::parse::Parse for DelegateTarget {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let enum_ident = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        let member = input.parse()?;
+        Ok(Self { enum_ident, member })
+    }
+}
+
+/// Generates the getters of a single-field tuple struct wrapping an enum, by
+/// invoking the `macro_rules!` generated along with the getters of the enum.
+fn impl_for_wrapper(ast: &syn::DeriveInput, delegate: &DelegateTarget) -> syn::Result<proc_macro2::TokenStream> {
+    let fail_message = "`enum_fields_delegate` is only applicable to tuple `struct`s with a single field";
+    let single_field = matches!(
+        &ast.data,
+        syn::Data::Struct(syn::DataStruct { fields: syn::Fields::Unnamed(fields), .. }) if fields.unnamed.len() == 1
+    );
+    if !single_field {
+        return Err(syn::Error::new(ast.ident.span(), fail_message));
+    }
+
+    if delegate.member.index != 0 {
+        return Err(syn::Error::new(delegate.member.span, format!(
+            "`{}` has a single field, so the enum must be its field `0`", ast.ident,
+        )));
+    }
+
+    if !ast.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&ast.generics, "`enum_fields_delegate` doesn't support generic wrappers"));
+    }
+
+    // Whether the field actually is the enum is left to the compiler, when
+    // the generated getters call the ones of the enum.
+    let wrapper = &ast.ident;
+    let member = &delegate.member;
+    let delegate_macro = delegate_macro_ident(&delegate.enum_ident);
+    Ok(quote! {
+        #delegate_macro!(#wrapper, #member);
+    })
+}
+
+/// A single occurrence of a field inside one of the variants of the enum.
+struct FieldOccurrence<'a> {
+    /// The index of the variant containing the field.
+    variant: usize,
+
+    /// How the field is accessed inside the variant, i.e. by name or position.
+    member: syn::Member,
+
+    field: &'a syn::Field,
+
+    attributes: FieldAttributes,
+}
+
+/// The fields of the enum along with their occurrences, in the order in which
+/// they first appear, so the methods are generated in a deterministic order.
+type AvailableFields<'a> = Vec<(String, Vec<FieldOccurrence<'a>>)>;
+
+/// Collects the fields of the enum, which are restricted to the ones listed in
+/// `only` if specified.
+fn collect_available_fields<'a>(
+    enum_data: &'a syn::DataEnum,
+    variant_attributes: &[VariantAttributes],
+    only: Option<&[Ident]>,
+) -> syn::Result<AvailableFields<'a>> {
+    let mut fields: AvailableFields = Vec::new();
+
+    // The index of each field in `fields` along with the key of its type, so
+    // neither is recomputed for every occurrence in enums with many variants.
+    let mut indices: HashMap<String, (usize, String)> = HashMap::new();
+
+    for (variant, variant_data) in enum_data.variants.iter().enumerate() {
+        if variant_attributes[variant].ignore {
+            continue;
+        }
+
+        for (position, field) in variant_data.fields.iter().enumerate() {
+            let attributes = FieldAttributes::parse(&field.attrs)?;
+            if attributes.skip {
+                continue;
+            }
+
+            let (ident, member) = match &field.ident {
+                Some(field_ident) => (field_ident.unraw().to_string(), syn::Member::Named(field_ident.clone())),
+                None => (format!("field_{position}"), syn::Member::Unnamed(position.into())),
+            };
+
+            let ident = match &attributes.rename {
+                Some(rename) => rename.unraw().to_string(),
+                None => ident,
+            };
+
+            if only.is_some_and(|only| !only.iter().any(|listed| listed.unraw() == ident)) {
+                continue;
+            }
+
+            let type_key = type_key(&field.ty);
+            let (index, first_type_key) = indices.entry(ident.clone()).or_insert_with(|| {
+                fields.push((ident, Vec::new()));
+                (fields.len() - 1, type_key.clone())
+            });
+            let occurrences = &mut fields[*index].1;
+
+            if let Some(first) = occurrences.first() {
+                if first.member != member {
+                    let first_variant = &enum_data.variants[first.variant].ident;
+                    return Err(syn::Error::new(field_span(field), format!(
+                        "this field has the same getter as the field `{}` in variant `{first_variant}`, \
+                        consider renaming either using `#[enum_fields(rename = \"...\")]`",
+                        member_name(&first.member),
+                    )));
+                }
+
+                if *first_type_key != type_key {
+                    // Positional fields are keyed on both their position and
+                    // type, so variants with a different type at this position
+                    // don't share it.
+                    let Some(field_ident) = &field.ident else {
+                        continue;
+                    };
+
+                    let first_variant = &enum_data.variants[first.variant].ident;
+                    if is_same_type_ignoring_lifetimes(&first.field.ty, &field.ty) {
+                        return Err(syn::Error::new_spanned(&field.ty, format!(
+                            "field `{field_ident}` has a different lifetime than in variant `{first_variant}`, where it is `{}`; \
+                            the lifetimes must be the same for the field to share a getter, or the field must be renamed",
+                            type_name(&first.field.ty),
+                        )));
+                    }
+
+                    return Err(syn::Error::new_spanned(&field.ty, format!(
+                        "field `{field_ident}` has a different type than in variant `{first_variant}`, where it is `{}`; \
+                        the types must match, or the field must be renamed",
+                        type_name(&first.field.ty),
+                    )));
+                }
+            }
+
+            occurrences.push(FieldOccurrence { variant, member, field, attributes });
+        }
+    }
+
+    for listed in only.unwrap_or_default() {
+        let listed_name = listed.unraw().to_string();
+        if !fields.iter().any(|(name, _)| *name == listed_name) {
+            return Err(syn::Error::new(listed.span(), format!(
+                "`only` lists the field `{listed_name}`, which none of the variants have"
+            )));
+        }
+    }
+
+    Ok(fields)
+}
+
+/// The span to point at in diagnostics about a field, which is its name, or
+/// its type for positional fields.
+fn field_span(field: &syn::Field) -> Span {
+    match &field.ident {
+        Some(ident) => ident.span(),
+        None => field.ty.span(),
+    }
+}
+
+fn member_name(member: &syn::Member) -> String {
+    match member {
+        syn::Member::Named(ident) => ident.unraw().to_string(),
+        syn::Member::Unnamed(index) => index.index.to_string(),
+    }
+}
+
+/// Creates the bound of a method requiring the type of a field to implement
+/// the `bound`, e.g. `Default`. The bound is higher-ranked, which defers
+/// checking it to the callers of the method: a bound on a concrete type that
+/// doesn't hold is an error otherwise, even if the method is never called.
+fn method_bound(ty: &syn::Type, bound: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    // The bound points at the field as well, so errors of callers do too.
+    let bound = bound.into_iter().map(|mut token| {
+        token.set_span(ty.span());
+        token
+    });
+    quote_spanned! { ty.span()=> for<'__enum_fields> #ty: #(#bound)* }
+}
+
+/// Returns the spelling of the type by which the types of the occurrences of
+/// a field are compared. Besides whitespace, the spelling is normalized by
+/// stripping the paths of the types of the prelude.
+fn type_key(ty: &syn::Type) -> String {
+    strip_prelude_paths(quote!(#ty)).to_string()
+}
+
+/// Returns whether the types only differ in their named lifetimes, e.g.
+/// `&'a str` and `&'b str`.
+fn is_same_type_ignoring_lifetimes(a: &syn::Type, b: &syn::Type) -> bool {
+    let a = erase_lifetimes(strip_prelude_paths(quote!(#a)));
+    let b = erase_lifetimes(strip_prelude_paths(quote!(#b)));
+    a.to_string() == b.to_string()
+}
+
+/// The types of the prelude that are commonly spelled by their full path as
+/// well, along with the module defining them.
+const PRELUDE_TYPES: &[(&str, &str)] = &[
+    ("boxed", "Box"),
+    ("option", "Option"),
+    ("result", "Result"),
+    ("string", "String"),
+    ("vec", "Vec"),
+];
+
+/// Strips the paths of the types of the prelude in the tokens, e.g. both
+/// `std::vec::Vec<T>` and `::alloc::vec::Vec<T>` become `Vec<T>`.
+fn strip_prelude_paths(tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+    let is_colon = |index: usize| matches!(tokens.get(index), Some(TokenTree::Punct(punct)) if punct.as_char() == ':');
+    let is_ident = |index: usize, names: &[&str]| matches!(tokens.get(index), Some(TokenTree::Ident(ident)) if names.iter().any(|name| ident == name));
+
+    let mut output = proc_macro2::TokenStream::new();
+    let mut index = 0;
+    while index < tokens.len() {
+        // The path must not continue another one, like `crate::std::vec::Vec`.
+        let start = if index > 0 && is_colon(index - 1) {
+            None
+        } else if is_colon(index) && is_colon(index + 1) {
+            Some(index + 2)
+        } else {
+            Some(index)
+        };
+
+        let prelude_type = start.filter(|&start| {
+            is_ident(start, &["std", "core", "alloc"]) && is_colon(start + 1) && is_colon(start + 2)
+                && is_colon(start + 4) && is_colon(start + 5)
+                && PRELUDE_TYPES.iter().any(|(module, name)| is_ident(start + 3, &[module]) && is_ident(start + 6, &[name]))
+        });
+
+        if let Some(start) = prelude_type {
+            output.extend([tokens[start + 6].clone()]);
+            index = start + 7;
+            continue;
+        }
+
+        match &tokens[index] {
+            TokenTree::Group(group) => {
+                let stripped = Group::new(group.delimiter(), strip_prelude_paths(group.stream()));
+                output.extend([TokenTree::Group(stripped)]);
+            }
+            token => output.extend([token.clone()]),
+        }
+        index += 1;
+    }
+
+    output
+}
+
+/// Replaces every lifetime in the tokens with `'_`.
+fn erase_lifetimes(tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let mut output = proc_macro2::TokenStream::new();
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                if let Some(TokenTree::Ident(..)) = tokens.peek() {
+                    tokens.next();
+                }
+                output.extend(quote! { '_ });
+            }
+            TokenTree::Group(group) => {
+                let erased = Group::new(group.delimiter(), erase_lifetimes(group.stream()));
+                output.extend([TokenTree::Group(erased)]);
+            }
+            token => output.extend([token]),
+        }
+    }
+
+    output
+}
+
+/// Whether the `tokens`, e.g. of a type, use one of the generic parameters,
+/// which items nested in the generated methods, like constants, can't.
+fn uses_generics(tokens: proc_macro2::TokenStream, generics: &syn::Generics) -> bool {
+    let mut tokens = tokens.into_iter();
+    while let Some(token) = tokens.next() {
+        let used = match token {
+            TokenTree::Punct(punct) if punct.as_char() == '\'' => match tokens.next() {
+                Some(TokenTree::Ident(ident)) => generics.lifetimes().any(|param| param.lifetime.ident == ident),
+                _ => false,
+            },
+            TokenTree::Ident(ident) => generics.type_params().any(|param| param.ident == ident)
+                || generics.const_params().any(|param| param.ident == ident),
+            TokenTree::Group(group) => uses_generics(group.stream(), generics),
+            TokenTree::Punct(_) | TokenTree::Literal(_) => false,
+        };
+
+        if used {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Returns the last segment of a path type, e.g. `String` for
+/// `std::string::String`.
+fn last_path_segment(ty: &syn::Type) -> Option<&syn::PathSegment> {
+    match ty {
+        syn::Type::Path(type_path) if type_path.qself.is_none() => type_path.path.segments.last(),
+        _ => None,
+    }
+}
+
+/// Returns whether the type is a `String`, optionally prefixed with its path.
+fn is_string_type(ty: &syn::Type) -> bool {
+    last_path_segment(ty)
+        .is_some_and(|segment| segment.ident == "String" && segment.arguments.is_empty())
+}
+
+/// Returns whether the type is a shared reference, e.g. `&'a str`.
+fn is_shared_reference(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Reference(reference) if reference.mutability.is_none())
+}
+
+/// Returns whether the type is a `PathBuf`, optionally prefixed with its path.
+fn is_path_buf_type(ty: &syn::Type) -> bool {
+    last_path_segment(ty)
+        .is_some_and(|segment| segment.ident == "PathBuf" && segment.arguments.is_empty())
+}
+
+/// Returns `T` if the type is an `Option<T>`.
+fn option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let segment = last_path_segment(ty)?;
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    single_type_argument(segment)
+}
+
+/// Returns `T` if the type is a `Vec<T>`.
+fn vec_element_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let segment = last_path_segment(ty)?;
+    if segment.ident != "Vec" {
+        return None;
+    }
+
+    single_type_argument(segment)
+}
+
+/// The type a smart pointer dereferences to, for `#[enum_fields(deref)]`.
+#[derive(Clone, Copy)]
+struct DerefTarget<'a> {
+    target: &'a syn::Type,
+
+    /// Whether the pointer can be dereferenced mutably, i.e. is a `Box`.
+    mutable: bool,
+}
+
+/// Returns the type `T` of a `Box<T>`, `Rc<T>`, `Arc<T>` or `Cow<'_, T>`.
+fn deref_target(ty: &syn::Type) -> Option<DerefTarget<'_>> {
+    let segment = last_path_segment(ty)?;
+    let (target, mutable) = match segment.ident.to_string().as_str() {
+        "Box" => (single_type_argument(segment)?, true),
+        "Rc" | "Arc" => (single_type_argument(segment)?, false),
+        "Cow" => (cow_type_argument(segment)?, false),
+        _ => return None,
+    };
+
+    Some(DerefTarget { target, mutable })
+}
+
+/// Returns the type argument of a `Cow<'a, T>`, after its lifetime.
+fn cow_type_argument(segment: &syn::PathSegment) -> Option<&syn::Type> {
+    let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+
+    let mut arguments = arguments.args.iter();
+    match (arguments.next(), arguments.next(), arguments.next()) {
+        (Some(syn::GenericArgument::Lifetime(..)), Some(syn::GenericArgument::Type(inner_type)), None) => Some(inner_type),
+        _ => None,
+    }
+}
+
+/// Returns the generic argument of a path segment with a single type
+/// argument, e.g. `T` for `Option<T>`.
+fn single_type_argument(segment: &syn::PathSegment) -> Option<&syn::Type> {
+    let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+
+    match arguments.args.first() {
+        Some(syn::GenericArgument::Type(inner_type)) if arguments.args.len() == 1 => Some(inner_type),
+        _ => None,
+    }
+}
+
+/// Formats the type for use in diagnostics, without the spacing between the
+/// tokens `quote` would otherwise emit, e.g. `Vec<u8>` instead of `Vec < u8 >`.
+fn type_name(ty: &syn::Type) -> String {
+    quote!(#ty).to_string()
+        .replace(" < ", "<")
+        .replace("< ", "<")
+        .replace(" <", "<")
+        .replace(" >", ">")
+        .replace(" :: ", "::")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+}
+
+/// The names of the methods of commonly derived traits, such as `Clone::clone`,
+/// which a getter would shadow when called as a method.
+const RESERVED_METHOD_NAMES: &[&str] = &["clone", "eq", "cmp", "fmt", "hash", "partial_cmp", "default"];
+
+/// Keeps track of the names of the generated methods, to report any clashes
+/// as a compile error instead of emitting duplicate definitions.
+#[derive(Default)]
+struct MethodNames {
+    names: HashMap<String, String>,
+}
+
+impl MethodNames {
+    /// Registers the method `name`, generated for the item described by
+    /// `origin` (e.g. "field `name`").
+    fn claim(&mut self, name: &str, origin: String, span: Span) -> syn::Result<()> {
+        if let Some(existing) = self.names.get(name) {
+            return Err(syn::Error::new(span, format!(
+                "the method `{name}` generated for {origin} clashes with the one generated for {existing}"
+            )));
+        }
+
+        self.names.insert(name.to_string(), origin);
+        Ok(())
+    }
+}
+
+/// The names of the getter and mutable getter of a field, along with their
+/// identifiers.
+struct GetterNames {
+    getter_name: String,
+
+    getter_ident: Ident,
+
+    mut_name: String,
+
+    mut_ident: Ident,
+}
+
+/// Determines the names of the getters of the field `field_name`, honoring the
+/// `getter_prefix`, `getter_suffix` and `mut_suffix` of the enum or union.
+/// Without them, the getter is `field_ident`, which may be a raw identifier.
+fn getter_names(attributes: &EnumAttributes, field_name: &str, field_ident: &Ident, field_span: Span) -> syn::Result<GetterNames> {
+    let (prefixed_name, prefixed_ident) = match &attributes.getter_prefix {
+        Some(prefix) => {
+            let prefixed_name = format!("{}{field_name}", prefix.value());
+            let ident = syn::parse_str::<Ident>(&prefixed_name).map_err(|_| syn::Error::new(prefix.span(), format!(
+                "`getter_prefix` must form a valid identifier with the field `{field_name}`"
+            )))?;
+            (prefixed_name, ident)
+        }
+        None => (field_name.to_string(), field_ident.clone()),
+    };
+
+    // The suffix of the getters doesn't apply to the mutable getters, which
+    // have their own suffix.
+    let (getter_name, getter_ident) = match &attributes.getter_suffix {
+        Some(suffix) => {
+            let getter_name = format!("{prefixed_name}{}", suffix.value());
+            let ident = syn::parse_str::<Ident>(&getter_name).map_err(|_| syn::Error::new(suffix.span(), format!(
+                "`getter_suffix` must form a valid identifier with the field `{field_name}`"
+            )))?;
+            (getter_name, ident)
+        }
+        None => (prefixed_name.clone(), prefixed_ident),
+    };
+
+    let (mut_name, mut_ident) = match &attributes.mut_suffix {
+        Some(suffix) => {
+            let mut_name = format!("{prefixed_name}{}", suffix.value());
+            let ident = syn::parse_str::<Ident>(&mut_name).map_err(|_| syn::Error::new(suffix.span(), format!(
+                "`mut_suffix` must form a valid identifier with the field `{field_name}`"
+            )))?;
+            (mut_name, ident)
+        }
+        None => {
+            let mut_name = format!("{prefixed_name}_mut");
+            let ident = Ident::new(&mut_name, Span::call_site());
+            (mut_name, ident)
+        }
+    };
+
+    if RESERVED_METHOD_NAMES.contains(&getter_name.as_str()) {
+        return Err(syn::Error::new(field_span, format!(
+            "the getter `{getter_name}` would shadow the method of a commonly derived trait, \
+            consider renaming the field using `#[enum_fields(rename = \"...\")]`"
+        )));
+    }
+
+    Ok(GetterNames { getter_name, getter_ident, mut_name, mut_ident })
+}
+
+/// Collects the `#[cfg(...)]` attributes of a variant, which are forwarded to
+/// the code generated for it so it is configured out along with the variant.
+fn cfg_attributes(attrs: &[syn::Attribute]) -> proc_macro2::TokenStream {
+    attrs.iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+        .map(|attr| quote! { #attr })
+        .collect()
+}
+
+/// Creates the pattern that matches the variant, regardless of its fields.
+fn variant_pattern(variant: &syn::Variant) -> proc_macro2::TokenStream {
+    let name = &variant.ident;
+    match &variant.fields {
+        syn::Fields::Named(..) => quote! { Self::#name{ .. } },
+        syn::Fields::Unnamed(..) => quote! { Self::#name(..) },
+        syn::Fields::Unit => quote! { Self::#name },
+    }
+}
+
+/// A field shared by one or more variants, for which methods are generated.
+struct SharedField<'a> {
+    /// The name of the field, from which the names of the methods are derived.
+    name: String,
+
+    /// The identifier of the getter, also used to bind positional fields.
+    ident: Ident,
+
+    ty: &'a syn::Type,
+
+    span: Span,
+
+    /// Whether every variant has the field.
+    present_everywhere: bool,
+
+    /// Whether the getters return the field directly, instead of an `Option`,
+    /// which `#[enum_fields(always_optional)]` prevents even for fields that
+    /// are present everywhere.
+    direct: bool,
+
+    /// Whether every variant has the field, except the ones ignored using
+    /// `#[enum_fields(ignore)]`. The getter then returns the field directly as
+    /// well, and the default of the field for the ignored variants.
+    present_unless_ignored: bool,
+
+    occurrences: Vec<FieldOccurrence<'a>>,
+
+    /// The pattern binding the field in each variant that has it, computed
+    /// once and shared by the `match` of every generated method. The other
+    /// variants use the pattern of [`EnumInfo::patterns`].
+    arms: Vec<Option<VariantArm>>,
+}
+
+/// The part of the pattern binding a field in each variant that has it, e.g.
+/// `0: field_0`, along with the binding, to bind several fields at once.
+type FieldBindings = Vec<Option<(proc_macro2::TokenStream, Ident)>>;
+
+/// The pattern matching a variant inside the methods of a [`SharedField`],
+/// binding the field.
+struct VariantArm {
+    pattern: proc_macro2::TokenStream,
+
+    binding: Ident,
+}
+
+impl<'a> SharedField<'a> {
+    fn new(info: &EnumInfo, name: String, occurrences: Vec<FieldOccurrence<'a>>, always_optional: bool) -> Self {
+        // Keywords like `type` can only be used as a field name in their raw
+        // form, i.e. `r#type`, so the same goes for the getter, whether it is
+        // named after the field or renamed to a keyword.
+        let raw = match (&occurrences[0].attributes.rename, &occurrences[0].member) {
+            (Some(ident), _) | (None, syn::Member::Named(ident)) => ident.to_string().starts_with("r#"),
+            (None, syn::Member::Unnamed(_)) => false,
+        };
+        let ident = if raw {
+            Ident::new_raw(&name, Span::call_site())
+        } else {
+            Ident::new(&name, Span::call_site())
+        };
+
+        // The occurrences are in the order of the variants, so they are
+        // matched up in a single pass, even for enums with many variants.
+        let mut remaining = occurrences.iter().peekable();
+        let arms = info.data.variants.iter().enumerate()
+            .map(|(variant_index, variant)| {
+                let occurrence = remaining.next_if(|occurrence| occurrence.variant == variant_index)?;
+
+                // Named fields are bound by their own name, positional fields
+                // by the name of the getter.
+                let variant_name = &variant.ident;
+                Some(match &occurrence.member {
+                    syn::Member::Named(variant_field_ident) => VariantArm {
+                        pattern: quote! { Self::#variant_name{ #variant_field_ident, .. } },
+                        binding: variant_field_ident.clone(),
+                    },
+                    syn::Member::Unnamed(index) => VariantArm {
+                        pattern: quote! { Self::#variant_name{ #index: #ident, .. } },
+                        binding: ident.clone(),
+                    },
+                })
+            })
+            .collect();
+
+        let present_everywhere = occurrences.len() == info.data.variants.len();
+        Self {
+            ty: &occurrences[0].field.ty,
+            span: field_span(occurrences[0].field),
+            present_everywhere,
+            direct: present_everywhere && !always_optional,
+            present_unless_ignored: !present_everywhere && occurrences.len() == info.participating_variants,
+            name,
+            ident,
+            occurrences,
+            arms,
+        }
+    }
+}
+
+/// Creates the arms of the `match self` in a getter for the `field`. The
+/// `value` function produces the expression of the arm given the binding of
+/// the field, and is wrapped in `Some` unless the getters return the field
+/// directly.
+fn getter_arms(
+    info: &EnumInfo,
+    field: &SharedField,
+    value: impl Fn(&Ident) -> proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let present = |binding: &Ident| {
+        let value = value(binding);
+        if field.direct {
+            value
+        } else {
+            quote! { Some(#value) }
+        }
+    };
+
+    match_arms(info, field, present, |_| quote! { None })
+}
+
+/// Creates the arms of a `match self` over the `field`. The `present` function
+/// produces the expression of the arm given the binding of the field, and the
+/// `absent` function produces the expression for the variants without it.
+///
+/// For `#[non_exhaustive]` enums, a wildcard arm is added as well, unless every
+/// variant has the field. Its expression is produced by `absent` without a
+/// variant.
+///
+/// Variants ignored using `#[enum_fields(ignore)]` are absent as well. When
+/// every other variant has the field, the getter returns its default for them,
+/// which is why such fields require one.
+fn match_arms(
+    info: &EnumInfo,
+    field: &SharedField,
+    present: impl Fn(&Ident) -> proc_macro2::TokenStream,
+    absent: impl Fn(Option<&syn::Variant>) -> proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let mut variants = proc_macro2::TokenStream::new();
+
+    for (variant_index, (variant, arm)) in info.data.variants.iter().zip(&field.arms).enumerate() {
+        let cfg = &info.cfgs[variant_index];
+        let (pattern, value) = match arm {
+            Some(arm) => (&arm.pattern, present(&arm.binding)),
+            None => (&info.patterns[variant_index], absent(Some(variant))),
+        };
+
+        // The arm is appended directly instead of quoted on its own first,
+        // which is noticeably faster for enums with many variants.
+        cfg.to_tokens(&mut variants);
+        pattern.to_tokens(&mut variants);
+        variants.extend(quote! { => });
+        value.to_tokens(&mut variants);
+        variants.extend(quote! { , });
+    }
+
+    if info.non_exhaustive && !field.present_everywhere {
+        let absent = absent(None);
+        variants.extend(quote! {
+            #[allow(unreachable_patterns)]
+            _ => #absent,
+        });
+    }
+
+    variants
+}
+
+/// Creates the arms of the `match self` in the mutable getter of a `field`,
+/// which some of the variants don't allow mutating using
+/// `#[enum_fields(no_mut)]`. The getter returns `None` for those variants, so
+/// the `value` is always wrapped in `Some`.
+///
+/// This is also used by the consuming extractor of `#[enum_fields(from)]`,
+/// which returns `None` for the variants holding other fields as well.
+fn mutable_getter_arms(
+    info: &EnumInfo,
+    field: &SharedField,
+    no_mut_variants: &[usize],
+    value: impl Fn(&Ident) -> proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let mut variants = proc_macro2::TokenStream::new();
+
+    for (variant_index, arm) in field.arms.iter().enumerate() {
+        let cfg = &info.cfgs[variant_index];
+        let (pattern, value) = match arm {
+            Some(arm) if !no_mut_variants.contains(&variant_index) => {
+                let value = value(&arm.binding);
+                (&arm.pattern, quote! { Some(#value) })
+            }
+            // The field isn't bound for such variants, as it would be unused.
+            _ => (&info.patterns[variant_index], quote! { None }),
+        };
+
+        cfg.to_tokens(&mut variants);
+        pattern.to_tokens(&mut variants);
+        variants.extend(quote! { => });
+        value.to_tokens(&mut variants);
+        variants.extend(quote! { , });
+    }
+
+    if info.non_exhaustive {
+        variants.extend(quote! {
+            #[allow(unreachable_patterns)]
+            _ => None,
+        });
+    }
+
+    variants
+}
+
+/// The enum for which the methods are generated.
+struct EnumInfo<'a> {
+    data: &'a syn::DataEnum,
+
+    /// Whether the enum is annotated with `#[non_exhaustive]`.
+    non_exhaustive: bool,
+
+    /// The number of variants that aren't ignored using
+    /// `#[enum_fields(ignore)]`, and therefore take part in sharing fields.
+    participating_variants: usize,
+
+    /// The `#[cfg(...)]` attributes of each variant, computed once instead of
+    /// for every field.
+    cfgs: Vec<proc_macro2::TokenStream>,
+
+    /// The pattern matching each variant regardless of its fields.
+    patterns: Vec<proc_macro2::TokenStream>,
+}
+
+/// The parts of the signatures of the generated methods that are shared by
+/// all of them, as configured by the attributes of the enum.
+struct MethodAttributes {
+    vis: syn::Visibility,
+
+    /// Either `const` or nothing, for the getters only.
+    constness: proc_macro2::TokenStream,
+
+    inline: proc_macro2::TokenStream,
+
+    /// Either `#[must_use]` or nothing, for the immutable accessors only.
+    must_use: proc_macro2::TokenStream,
+
+    allow: proc_macro2::TokenStream,
+
+    /// Either `#[doc(hidden)]` or nothing.
+    doc_hidden: proc_macro2::TokenStream,
+}
+
+impl MethodAttributes {
+    fn new(ast: &syn::DeriveInput, attributes: &EnumAttributes) -> Self {
+        let vis = match &attributes.vis {
+            Some(vis) => vis.clone(),
+            None if attributes.inherit_vis => ast.vis.clone(),
+            None => syn::parse_quote!(pub),
+        };
+
+        let constness = if attributes.const_getters {
+            quote! { const }
+        } else {
+            quote! {}
+        };
+
+        // The methods are trivial, so they should be inlined across crates too.
+        let inline = if attributes.inline_always {
+            quote! { #[inline(always)] }
+        } else {
+            quote! { #[inline] }
+        };
+
+        // Only the immutable accessors are `#[must_use]`, as the mutable ones
+        // are also used to modify the enum in place.
+        let must_use = if attributes.must_use {
+            quote! { #[must_use] }
+        } else {
+            quote! {}
+        };
+
+        // Lints the user allowed are allowed on every generated method,
+        // instead of on the whole enum.
+        let allow = &attributes.allow;
+        let allow = if allow.is_empty() {
+            quote! {}
+        } else {
+            quote! { #[allow(#(#allow),*)] }
+        };
+
+        let doc_hidden = if attributes.doc_hidden {
+            quote! { #[doc(hidden)] }
+        } else {
+            quote! {}
+        };
+
+        Self { vis, constness, inline, must_use, allow, doc_hidden }
+    }
+}
+
+fn impl_for_input(ast: &syn::DeriveInput) -> TokenStream {
+    let fail_message = "`EnumFields` is only applicable to `enum`s, \
+        or to `union`s annotated with `#[enum_fields(unsafe_getters)]`";
+    match &ast.data {
+        syn::Data::Enum(data_enum) => impl_for_enum(ast, data_enum)
+            .unwrap_or_else(syn::Error::into_compile_error)
+            .into(),
+        syn::Data::Union(data_union) => impl_for_union(ast, data_union, fail_message)
+            .unwrap_or_else(syn::Error::into_compile_error)
+            .into(),
+        syn::Data::Struct(data_struct) => syn::Error::new(data_struct.struct_token.span, fail_message).to_compile_error().into(),
+    }
+}
+
+/// Generates the getters of a `union`, which are `unsafe` as reading a field
+/// of a union is.
+fn impl_for_union(
+    ast: &syn::DeriveInput,
+    union_data: &syn::DataUnion,
+    fail_message: &str,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let attributes = EnumAttributes::parse(&ast.attrs)?;
+    if !attributes.unsafe_getters {
+        return Err(syn::Error::new(union_data.union_token.span, fail_message));
+    }
+
+    // Options about variants or the methods that can't be generated for the
+    // fields of a union are rejected, instead of silently ignored.
+    let unsupported = [
+        (attributes.always_optional, "always_optional"),
+        (attributes.as_ref, "as_ref"),
+        (attributes.as_variant, "as_variant"),
+        (attributes.compare, "compare"),
+        (attributes.const_getters, "const_getters"),
+        (attributes.case != VariantCase::Snake, "case"),
+        (attributes.eq_field.is_some(), "eq_field"),
+        (attributes.flatten_option, "flatten_option"),
+        (attributes.getter_result.is_some(), "getter_result"),
+        (attributes.into, "into"),
+        (attributes.iter, "iter"),
+        (attributes.map, "map"),
+        (attributes.only.is_some(), "only"),
+        (attributes.or, "or"),
+        (attributes.path_accessors, "path_accessors"),
+        (attributes.replace, "replace"),
+        (attributes.setters, "setters"),
+        (attributes.shared_key, "shared_key"),
+        (attributes.split_mut, "split_mut"),
+        (attributes.str_accessors, "str_accessors"),
+        (attributes.swap, "swap"),
+        (attributes.take, "take"),
+        (attributes.transparent, "transparent"),
+        (attributes.try_getters, "try"),
+        (attributes.unwrap, "unwrap"),
+        (attributes.visit, "visit"),
+        (attributes.with, "with"),
+    ].into_iter().find_map(|(unsupported, name)| unsupported.then_some(name));
+
+    if let Some(option) = unsupported {
+        let span = option_span(&ast.attrs, option).unwrap_or_else(Span::call_site);
+        return Err(syn::Error::new(span, format!("`{option}` can't be used on `union`s")));
+    }
+
+    let MethodAttributes { vis, inline, must_use, allow, doc_hidden, .. } = MethodAttributes::new(ast, &attributes);
+
+    let mut methods = proc_macro2::TokenStream::new();
+    let mut trait_methods = proc_macro2::TokenStream::new();
+    let mut trait_impl_methods = proc_macro2::TokenStream::new();
+    let mut method_names = MethodNames::default();
+    let self_type = self_type(ast);
+
+    for field in &union_data.fields.named {
+        let field_attributes = FieldAttributes::parse(&field.attrs)?;
+        if field_attributes.skip {
+            continue;
+        }
+
+        let Some(field_ident) = &field.ident else {
+            continue;
+        };
+
+        let unsupported = [
+            (field_attributes.as_ref, "as_ref"),
+            (field_attributes.by_value, "by_value"),
+            (field_attributes.clone, "clone"),
+            (field_attributes.default.is_some(), "default"),
+            (!field_attributes.delegate.is_empty(), "delegate"),
+            (field_attributes.deref, "deref"),
+            (field_attributes.flatten_option, "flatten_option"),
+            (field_attributes.from, "from"),
+            (field_attributes.option_mut_inner, "option_mut_inner"),
+            (field_attributes.or_default, "or_default"),
+            (field_attributes.pin, "pin"),
+            (field_attributes.raw_ptr, "raw_ptr"),
+            (field_attributes.ty.is_some(), "ty"),
+        ].into_iter().find_map(|(unsupported, name)| unsupported.then_some(name));
+
+        if let Some(option) = unsupported {
+            let span = option_span(&field.attrs, option).unwrap_or_else(|| field_span(field));
+            return Err(syn::Error::new(span, format!("`{option}` can't be used on the fields of `union`s")));
+        }
+
+        let name_ident = field_attributes.rename.as_ref().unwrap_or(field_ident);
+        let field_name = name_ident.unraw().to_string();
+        let GetterNames { getter_name, getter_ident, mut_name, mut_ident } =
+            getter_names(&attributes, &field_name, name_ident, field_span(field))?;
+        method_names.claim(&getter_name, format!("field `{field_name}`"), field_span(field))?;
+
+        let field_type = &field.ty;
+        let vis = field_attributes.vis.as_ref().unwrap_or(&vis);
+        let doc_or = |default: String| field_attributes.getter_doc.as_ref().map_or(default, syn::LitStr::value);
+        let doc = doc_or(format!("Get the field `{field_name}` of this union"));
+
+        methods.extend(quote! {
+            #[doc = #doc]
+            ///
+            /// # Safety
+            /// The field must hold a valid value of its type, e.g. because it
+            /// is the field that was last written to.
+            #must_use
+            #inline
+            #allow
+            #doc_hidden
+            #vis unsafe fn #getter_ident(&self) -> & #field_type {
+                // SAFETY: The caller guarantees the field is valid.
+                unsafe { &self.#field_ident }
+            }
+        });
+
+        if is_visible_as_enum(vis, ast) {
+            trait_methods.extend(quote! {
+                #[doc = #doc]
+                ///
+                /// # Safety
+                /// The field must hold a valid value of its type.
+                #must_use
+                unsafe fn #getter_ident(&self) -> & #field_type;
+            });
+
+            trait_impl_methods.extend(quote! {
+                #inline
+                #allow
+                unsafe fn #getter_ident(&self) -> & #field_type {
+                    // SAFETY: The caller upholds the contract of the trait method.
+                    unsafe { #self_type::#getter_ident(self) }
+                }
+            });
+        }
+
+        if attributes.readonly || field_attributes.readonly || field_attributes.no_mut {
+            continue;
+        }
+
+        method_names.claim(&mut_name, format!("field `{field_name}`"), field_span(field))?;
+
+        let doc = doc_or(format!("Get the field `{field_name}` of this union mutably"));
+
+        methods.extend(quote! {
+            #[doc = #doc]
+            ///
+            /// # Safety
+            /// The field must hold a valid value of its type, e.g. because it
+            /// is the field that was last written to.
+            #inline
+            #allow
+            #doc_hidden
+            #vis unsafe fn #mut_ident(&mut self) -> &mut #field_type {
+                // SAFETY: The caller guarantees the field is valid.
+                unsafe { &mut self.#field_ident }
+            }
+        });
+    }
+
+    impl_blocks(ast, &attributes, methods, trait_methods, trait_impl_methods)
+}
+
+fn impl_for_enum(ast: &syn::DeriveInput, enum_data: &syn::DataEnum) -> syn::Result<proc_macro2::TokenStream> {
+    let attributes = EnumAttributes::parse(&ast.attrs)?;
+    if attributes.unsafe_getters {
+        return Err(syn::Error::new(Span::call_site(), "`unsafe_getters` can only be used on `union`s"));
+    }
+
+    if let Some(error_type) = attributes.getter_result.as_ref().filter(|_| !attributes.try_getters) {
+        return Err(syn::Error::new_spanned(error_type, "`getter_result` requires `try`, as it only affects the `try_<field>()` getters"));
+    }
+
+    let variant_attributes = enum_data.variants.iter()
+        .map(|variant| VariantAttributes::parse(&variant.attrs))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let info = EnumInfo {
+        data: enum_data,
+        non_exhaustive: ast.attrs.iter().any(|attr| attr.path().is_ident("non_exhaustive")),
+        participating_variants: variant_attributes.iter().filter(|attributes| !attributes.ignore).count(),
+        cfgs: enum_data.variants.iter().map(|variant| cfg_attributes(&variant.attrs)).collect(),
+        patterns: enum_data.variants.iter().map(variant_pattern).collect(),
+    };
+
+    // An enum without variants doesn't have any fields, and a `match self`
+    // without any arms doesn't compile for a reference to it, so the `impl`
+    // block is left empty.
+    if enum_data.variants.is_empty() {
+        let methods = proc_macro2::TokenStream::new();
+        return impl_blocks(ast, &attributes, methods.clone(), methods.clone(), methods);
+    }
+
+    // Collect available fields. Enums without any, like C-like enums, still
+    // get the methods of their variants.
+    let fields = collect_available_fields(enum_data, &variant_attributes, attributes.only.as_deref())?;
+
+    // All generated methods are collected into a single `impl` block.
+    let mut methods = proc_macro2::TokenStream::new();
+
+    // The getters of the companion trait and their implementations, if any.
+    let mut trait_methods = proc_macro2::TokenStream::new();
+    let mut trait_impl_methods = proc_macro2::TokenStream::new();
+    let mut method_names = MethodNames::default();
+    let self_type = self_type(ast);
+
+    // The `AsRef` implementations for the fields present in every variant,
+    // along with whether the field itself is annotated with `as_ref`.
+    let mut as_ref_impls: Vec<(String, bool, proc_macro2::TokenStream)> = Vec::new();
+
+    // The types of the fields present in every variant and the arms getting
+    // them, for `#[enum_fields(shared_key)]`.
+    let mut shared_key_fields: Vec<(&syn::Type, proc_macro2::TokenStream)> = Vec::new();
+
+    // The types of the mutable fields present in every variant, along with
+    // the part of the pattern binding them in each variant, for
+    // `#[enum_fields(split_mut)]`.
+    let mut split_mut_fields: Vec<(&syn::Type, FieldBindings)> = Vec::new();
+
+    // The `Deref` implementation for `#[enum_fields(transparent)]`.
+    let mut deref_impl = None;
+
+    if attributes.transparent {
+        if let Some(variant) = enum_data.variants.iter().find(|variant| variant.fields.len() != 1) {
+            return Err(syn::Error::new(variant.ident.span(), format!(
+                "`transparent` requires every variant to hold exactly one field, unlike `{}`", variant.ident,
+            )));
+        }
+
+        // `Deref` can't return `None`, so every variant has to have the field.
+        if let Some((variant, _)) = enum_data.variants.iter().zip(&variant_attributes).find(|(_, attributes)| attributes.ignore) {
+            return Err(syn::Error::new(variant.ident.span(), format!(
+                "`transparent` can't be combined with ignored variants, like `{}`", variant.ident,
+            )));
+        }
+    }
+
+    // Whether the field of `#[enum_fields(eq_field = "...")]` exists.
+    let mut eq_field_found = false;
+
+    // The getters forwarding to the field of a wrapper, for
+    // `#[enum_fields_delegate(...)]`.
+    let mut delegate_methods = proc_macro2::TokenStream::new();
+
+    let MethodAttributes { vis, constness, inline, must_use, allow, doc_hidden } = MethodAttributes::new(ast, &attributes);
+
+    // Methods named after variants in another case than `snake_case` aren't in
+    // `snake_case` either.
+    let allow_case = if attributes.case == VariantCase::Snake {
+        quote! {}
+    } else {
+        quote! { #[allow(non_snake_case)] }
+    };
+
+    for (variant_index, variant) in enum_data.variants.iter().enumerate() {
+        let variant_name = variant.ident.to_string();
+        let case_name = attributes.case.convert(&variant_name);
+
+        if fields.iter().any(|(field_name, _)| *field_name == case_name) {
+            return Err(syn::Error::new(variant.ident.span(), format!(
+                "variant `{variant_name}` is ambiguous with the field `{case_name}`, consider renaming either"
+            )));
+        }
+
+        let predicate_name = format!("is_{case_name}");
+        method_names.claim(&predicate_name, format!("variant `{variant_name}`"), variant.ident.span())?;
+
+        let predicate_ident = Ident::new(&predicate_name, Span::call_site());
+        let cfg = &info.cfgs[variant_index];
+        let pattern = &info.patterns[variant_index];
+        let doc = format!("Check whether this enum is the `{variant_name}` variant");
+
+        methods.extend(quote! {
+            #cfg
+            #[doc = #doc]
+            #must_use
+            #inline
+            #allow
+            #doc_hidden
+            #allow_case
+            #vis fn #predicate_ident(&self) -> bool {
+                matches!(self, #pattern)
+            }
+        });
+
+        if attributes.as_variant {
+            let as_name = format!("as_{case_name}");
+            let as_name_mut = format!("as_{case_name}_mut");
+            method_names.claim(&as_name, format!("variant `{variant_name}`"), variant.ident.span())?;
+            method_names.claim(&as_name_mut, format!("variant `{variant_name}`"), variant.ident.span())?;
+
+            let as_ident = Ident::new(&as_name, Span::call_site());
+            let as_ident_mut = Ident::new(&as_name_mut, Span::call_site());
+
+            // Both named and positional fields can be bound using braces,
+            // positional ones by the name of their positional getter.
+            let mut patterns = Vec::new();
+            let mut bindings = Vec::new();
+            for (position, field) in variant.fields.iter().enumerate() {
+                match &field.ident {
+                    Some(ident) => {
+                        patterns.push(quote! { #ident });
+                        bindings.push(ident.clone());
+                    }
+                    None => {
+                        let binding = Ident::new(&format!("field_{position}"), Span::call_site());
+                        let index = syn::Index::from(position);
+                        patterns.push(quote! { #index: #binding });
+                        bindings.push(binding);
+                    }
+                }
+            }
+
+            // A variant with a single field returns it directly, instead of
+            // wrapping it in a tuple.
+            let types: Vec<_> = variant.fields.iter().map(|field| &field.ty).collect();
+            let (ty, ty_mut, value) = match (types.as_slice(), bindings.as_slice()) {
+                ([ty], [binding]) => (quote! { &#ty }, quote! { &mut #ty }, quote! { #binding }),
+                _ => (quote! { (#(&#types),*) }, quote! { (#(&mut #types),*) }, quote! { (#(#bindings),*) }),
+            };
+
+            let variant_ident = &variant.ident;
+            let doc = format!("Get the fields of this enum if it's the `{variant_name}` variant");
+            let doc_mut = format!("Get the mutable fields of this enum if it's the `{variant_name}` variant");
+
+            methods.extend(quote! {
+                #cfg
+                #[doc = #doc]
+                #must_use
+                #inline
+                #allow
+                #doc_hidden
+                #allow_case
+                #vis fn #as_ident(&self) -> Option<#ty> {
+                    match self {
+                        Self::#variant_ident { #(#patterns),* } => Some(#value),
+                        #[allow(unreachable_patterns)]
+                        _ => None,
+                    }
+                }
+
+                #cfg
+                #[doc = #doc_mut]
+                #inline
+                #allow
+                #doc_hidden
+                #allow_case
+                #vis fn #as_ident_mut(&mut self) -> Option<#ty_mut> {
+                    match self {
+                        Self::#variant_ident { #(#patterns),* } => Some(#value),
+                        #[allow(unreachable_patterns)]
+                        _ => None,
+                    }
+                }
+            });
+        }
+    }
+
+    method_names.claim("variant_name", "the name of the variant".into(), Span::call_site())?;
+    method_names.claim("field_names", "the list of field names".into(), Span::call_site())?;
+    method_names.claim("field_count", "the number of fields".into(), Span::call_site())?;
+    method_names.claim("VARIANTS", "the list of variant names".into(), Span::call_site())?;
+
+    let mut all_variant_names = proc_macro2::TokenStream::new();
+    let mut variant_names = proc_macro2::TokenStream::new();
+    let mut field_names = proc_macro2::TokenStream::new();
+    let mut field_counts = proc_macro2::TokenStream::new();
+    for (variant_index, variant) in enum_data.variants.iter().enumerate() {
+        let cfg = &info.cfgs[variant_index];
+        let pattern = &info.patterns[variant_index];
+        let variant_name = variant.ident.unraw().to_string();
+        let names = variant.fields.iter()
+            .filter_map(|field| field.ident.as_ref())
+            .map(|ident| ident.unraw().to_string());
+
+        all_variant_names.extend(quote! {
+            #cfg
+            #variant_name,
+        });
+
+        variant_names.extend(quote! {
+            #cfg
+            #pattern => #variant_name,
+        });
+
+        field_names.extend(quote! {
+            #cfg
+            #pattern => &[#(#names),*],
+        });
+
+        let count = variant.fields.len();
+        field_counts.extend(quote! {
+            #cfg
+            #pattern => #count,
+        });
+    }
+
+    methods.extend(quote! {
+        /// The names of all variants of this enum, in declaration order
+        #doc_hidden
+        #vis const VARIANTS: &'static [&'static str] = &[#all_variant_names];
+
+        #must_use
+        #inline
+        #allow
+        #doc_hidden
+        #vis fn variant_name(&self) -> &'static str {
+            //! Get the name of this enum discriminant
+            match self {
+                #variant_names
+            }
+        }
+
+        #must_use
+        #inline
+        #allow
+        #doc_hidden
+        #vis fn field_names(&self) -> &'static [&'static str] {
+            //! Get the names of the fields of this enum discriminant, in declaration order
+            match self {
+                #field_names
+            }
+        }
+
+        #must_use
+        #inline
+        #allow
+        #doc_hidden
+        #vis fn field_count(&self) -> usize {
+            //! Get the number of fields of this enum discriminant, including positional ones
+            match self {
+                #field_counts
+            }
+        }
+    });
+
+    for (field_name, occurrences) in fields {
+        let field = SharedField::new(&info, field_name, occurrences, attributes.always_optional);
+        let field_name = &field.name;
+        let field_type = field.ty;
+        let field_span = field.span;
+        let field_present_everywhere = field.present_everywhere;
+        let field_direct = field.direct;
+
+        let readonly = attributes.readonly
+            || field.occurrences.iter().any(|occurrence| occurrence.attributes.readonly);
+
+        // Unlike a read-only field, a field without a mutable getter can still
+        // have setters. When only some of the variants don't allow mutating
+        // the field, the mutable getter returns `None` for those variants.
+        let no_mut_variants: Vec<usize> = field.occurrences.iter()
+            .filter(|occurrence| occurrence.attributes.no_mut)
+            .map(|occurrence| occurrence.variant)
+            .collect();
+        let no_mut = readonly || no_mut_variants.len() == field.occurrences.len();
+
+        let GetterNames {
+            getter_name,
+            getter_ident: field_name_ident,
+            mut_name: field_name_mut,
+            mut_ident: field_name_ident_mut,
+        } = getter_names(&attributes, field_name, &field.ident, field_span)?;
+
+        method_names.claim(&getter_name, format!("field `{field_name}`"), field_span)?;
+        if !no_mut {
+            method_names.claim(&field_name_mut, format!("field `{field_name}`"), field_span)?;
+        }
+
+        // Shared references are `Copy`, so they are returned by value instead
+        // of as a reference to the reference.
+        let reference = is_shared_reference(field_type);
+        // The deprecation of the field is forwarded to its methods, so their
+        // callers get the warning as well. The methods themselves still use
+        // the field, which shouldn't warn.
+        let deprecated = field.occurrences.iter()
+            .flat_map(|occurrence| &occurrence.field.attrs)
+            .find(|attr| attr.path().is_ident("deprecated"));
+        let allow_deprecated = deprecated.map(|_| quote! { #[allow(deprecated)] });
+        let deprecated = deprecated.map(|attr| quote! { #attr #allow_deprecated });
+
+        let by_value = reference || field.occurrences.iter().any(|occurrence| occurrence.attributes.by_value);
+        let vis = field.occurrences.iter()
+            .find_map(|occurrence| occurrence.attributes.vis.as_ref())
+            .unwrap_or(&vis);
+
+        let flatten_option = field.occurrences.iter()
+            .find(|occurrence| occurrence.attributes.flatten_option)
+            .map(|occurrence| option_inner_type(&occurrence.field.ty).ok_or_else(|| {
+                syn::Error::new_spanned(&occurrence.field.ty, "`flatten_option` can only be used on `Option<T>` fields")
+            }))
+            .transpose()?;
+
+        // On the enum, `flatten_option` applies to every `Option<T>` field that
+        // would otherwise be wrapped in another `Option`, unless it has a
+        // default instead.
+        let has_default = field.occurrences.iter()
+            .any(|occurrence| occurrence.attributes.default.is_some() || occurrence.attributes.or_default);
+        let flatten_option = flatten_option.or_else(|| {
+            if attributes.flatten_option && !field_direct && !has_default {
+                option_inner_type(field_type)
+            } else {
+                None
+            }
+        });
+
+        let deref = field.occurrences.iter()
+            .find(|occurrence| occurrence.attributes.deref)
+            .map(|occurrence| deref_target(&occurrence.field.ty).ok_or_else(|| {
+                syn::Error::new_spanned(&occurrence.field.ty, "`deref` can only be used on `Box<T>`, `Rc<T>`, `Arc<T>` or `Cow<'_, T>` fields")
+            }))
+            .transpose()?;
+
+        // A default is only used for the variants without the field, so it
+        // doesn't affect fields that are present everywhere.
+        let default = field.occurrences.iter()
+            .find_map(|occurrence| occurrence.attributes.default.as_ref())
+            .filter(|_| !field_present_everywhere);
+        if let (Some(default), Some(_)) = (default, flatten_option) {
+            return Err(syn::Error::new(default.span(), "`default` can't be combined with `flatten_option`"));
+        }
+
+        // Like a default, `or_default` only affects fields that aren't present
+        // in every variant.
+        let or_default = field.occurrences.iter().any(|occurrence| occurrence.attributes.or_default)
+            && !field_present_everywhere;
+        if or_default {
+            let conflict = [
+                (by_value && !reference, "by_value"),
+                (flatten_option.is_some(), "flatten_option"),
+                (deref.is_some(), "deref"),
+                (default.is_some(), "default"),
+            ].into_iter().find_map(|(conflicts, name)| conflicts.then_some(name));
+
+            if let Some(conflict) = conflict {
+                return Err(syn::Error::new(field_span, format!("`or_default` can't be combined with `{conflict}`")));
+            }
+        }
+
+        // A field that every variant has, except the ignored ones, is returned
+        // directly by the getter as well, which therefore needs a value to
+        // return for the ignored variants instead of `None`.
+        let needs_default = field.present_unless_ignored && !attributes.always_optional
+            && flatten_option.is_none() && default.is_none() && !or_default;
+        if needs_default {
+            if let Some((ignored, _)) = enum_data.variants.iter().zip(&variant_attributes).find(|(_, attributes)| attributes.ignore) {
+                return Err(syn::Error::new(field_span, format!(
+                    "the field `{field_name}` is present in every variant except the ignored `{}`, so it needs \
+                    `#[enum_fields(default = \"...\")]` or `#[enum_fields(or_default)]` for the getter to return for it",
+                    ignored.ident,
+                )));
+            }
+        }
+
+        // The type of the getters can be overridden by a type the field coerces
+        // to, of which the correctness is left to the compiler.
+        let coerced_type = field.occurrences.iter()
+            .find_map(|occurrence| occurrence.attributes.ty.as_ref());
+        if let Some(coerced_type) = coerced_type {
+            let conflict = [
+                (by_value && !reference, "by_value"),
+                (flatten_option.is_some(), "flatten_option"),
+                (deref.is_some(), "deref"),
+                (default.is_some(), "default"),
+                (or_default, "or_default"),
+            ].into_iter().find_map(|(conflicts, name)| conflicts.then_some(name));
+
+            if let Some(conflict) = conflict {
+                return Err(syn::Error::new_spanned(coerced_type, format!("`ty` can't be combined with `{conflict}`")));
+            }
+        }
+
+        // Shared references are returned by value, unless they coerce to
+        // another type, which is returned by reference instead.
+        let by_value = by_value && coerced_type.is_none();
+
+        let coerce = |binding: &Ident, ty: proc_macro2::TokenStream| {
+            let value = Ident::new("value", Span::mixed_site());
+            quote! {
+                {
+                    let #value: #ty = #binding;
+                    #value
+                }
+            }
+        };
+
+        let (ty, variants) = match flatten_option {
+            // Both variants without the field and variants where it is `None`
+            // map to `None`, so the `Option` isn't wrapped in another one.
+            Some(inner_type) => {
+                let ty = if by_value {
+                    quote! { Option<#inner_type> }
+                } else {
+                    quote! { Option<& #inner_type> }
+                };
+
+                let variants = match_arms(&info, &field, |binding| {
+                    if by_value {
+                        quote! { *#binding }
+                    } else {
+                        quote! { #binding.as_ref() }
+                    }
+                }, |_| quote! { None });
+
+                (ty, variants)
+            }
+
+            // The getter returns the field by value, so variants without it
+            // can return a default.
+            None if or_default => {
+                let variants = match_arms(&info, &field, |binding| {
+                    quote! { ::std::clone::Clone::clone(#binding) }
+                }, |_| quote! { ::std::default::Default::default() });
+
+                (quote! { #field_type }, variants)
+            }
+
+            None => {
+                let target_type = coerced_type.or(deref.map(|deref| deref.target)).unwrap_or(field_type);
+                let value = |binding: &Ident| {
+                    if coerced_type.is_some() {
+                        return coerce(binding, quote! { & #target_type });
+                    }
+
+                    match (deref.is_some(), by_value) {
+                        (false, false) => quote! { #binding },
+                        (false, true) => quote! { *#binding },
+                        (true, false) => quote! { &**#binding },
+                        (true, true) => quote! { **#binding },
+                    }
+                };
+
+                match default {
+                    // The default is a constant, so a reference to it is
+                    // promoted to a `'static` one.
+                    Some(default_value) => {
+                        if uses_generics(target_type.to_token_stream(), &ast.generics) {
+                            return Err(syn::Error::new(default_value.span(), format!(
+                                "`default` can't be used for the field `{field_name}`, as its type uses the generic \
+                                parameters or lifetimes of the enum, which the constant holding the default can't"
+                            )));
+                        }
+
+                        let (ty, default) = if by_value {
+                            (quote! { #target_type }, quote! { DEFAULT })
+                        } else {
+                            (quote! { & #target_type }, quote! { &DEFAULT })
+                        };
+
+                        let variants = match_arms(&info, &field, value, |_| quote! {
+                            {
+                                const DEFAULT: #target_type = #default_value;
+                                #default
+                            }
+                        });
+
+                        (ty, variants)
+                    }
+
+                    None => {
+                        let ty = match (field_direct, by_value) {
+                            (true, false) => quote! { & #target_type },
+                            (true, true) => quote! { #target_type },
+                            (false, false) => quote! { Option<& #target_type> },
+                            (false, true) => quote! { Option<#target_type> },
+                        };
+
+                        (ty, getter_arms(&info, &field, value))
+                    }
+                }
+            }
+        };
+
+        // The `Copy` bound of a getter returning by value is placed on the
+        // method, so generic enums can still be used with other types as long
+        // as that getter isn't called.
+        let getter_where_clause = if or_default {
+            quote_spanned! { field_type.span()=>
+                where #field_type: ::std::default::Default + ::std::clone::Clone
+            }
+        } else if by_value && !reference {
+            let copied_type = match (flatten_option, deref) {
+                (None, Some(deref)) => deref.target,
+                _ => field_type,
+            };
+
+            quote_spanned! { copied_type.span()=> where #copied_type: Copy }
+        } else {
+            quote! {}
+        };
+
+        // Only a `Box` can be dereferenced mutably, the mutable getter of other
+        // smart pointers returns the pointer itself.
+        let deref_mut = deref.filter(|deref| deref.mutable);
+        let target_type_mut = coerced_type.or(deref_mut.map(|deref| deref.target)).unwrap_or(field_type);
+
+        // The mutable getter of a pinned field projects the pinned enum onto
+        // the field, which is only sound for the field itself.
+        let pin = field.occurrences.iter().any(|occurrence| occurrence.attributes.pin);
+        if pin {
+            let conflict = [
+                (deref.is_some(), "deref"),
+                (coerced_type.is_some(), "ty"),
+            ].into_iter().find_map(|(conflicts, name)| conflicts.then_some(name));
+
+            if let Some(conflict) = conflict {
+                return Err(syn::Error::new(field_span, format!("`pin` can't be combined with `{conflict}`")));
+            }
+        }
+
+        // The mutable getter of an `Option<T>` field can return the inner value
+        // instead, in which case variants without the field map to `None` as
+        // well.
+        let option_mut_inner = field.occurrences.iter()
+            .find(|occurrence| occurrence.attributes.option_mut_inner)
+            .map(|occurrence| option_inner_type(&occurrence.field.ty).ok_or_else(|| {
+                syn::Error::new_spanned(&occurrence.field.ty, "`option_mut_inner` can only be used on `Option<T>` fields")
+            }))
+            .transpose()?;
+        if option_mut_inner.is_some() {
+            let conflict = [
+                (pin, "pin"),
+                (deref.is_some(), "deref"),
+                (coerced_type.is_some(), "ty"),
+                (!no_mut_variants.is_empty(), "no_mut"),
+            ].into_iter().find_map(|(conflicts, name)| conflicts.then_some(name));
+
+            if let Some(conflict) = conflict {
+                return Err(syn::Error::new(field_span, format!("`option_mut_inner` can't be combined with `{conflict}`")));
+            }
+        }
+
+        let value_mut = |binding: &Ident| {
+            if pin {
+                quote! { unsafe { ::std::pin::Pin::new_unchecked(#binding) } }
+            } else if coerced_type.is_some() {
+                coerce(binding, quote! { &mut #target_type_mut })
+            } else if deref_mut.is_some() {
+                quote! { &mut **#binding }
+            } else {
+                quote! { #binding }
+            }
+        };
+
+        // Unless the getter transforms the field, its arms are the same as the
+        // ones of the mutable getter.
+        let variants_mut = if option_mut_inner.is_some() {
+            match_arms(&info, &field, |binding| {
+                quote! { #binding.as_mut() }
+            }, |_| quote! { None })
+        } else if !no_mut_variants.is_empty() {
+            mutable_getter_arms(&info, &field, &no_mut_variants, value_mut)
+        } else if by_value || flatten_option.is_some() || deref.is_some() || default.is_some() || or_default || coerced_type.is_some() || pin {
+            getter_arms(&info, &field, value_mut)
+        } else {
+            variants.clone()
+        };
+
+        let ty_mut = if pin {
+            quote! { ::std::pin::Pin<&mut #target_type_mut> }
+        } else {
+            quote! { &mut #target_type_mut }
+        };
+
+        let ty_mut = if let Some(inner_type) = option_mut_inner {
+            quote! { Option<&mut #inner_type> }
+        } else if field_direct && no_mut_variants.is_empty() {
+            ty_mut
+        } else {
+            quote! {
+                Option<#ty_mut>
+            }
+        };
+
+        // Cloning and defaulting the field calls trait methods, which can't be
+        // done in a `const fn`.
+        let constness = (!or_default).then_some(&constness);
+
+        // The documentation of the getters can be overridden, which then
+        // applies to both the getter and the mutable getter.
+        let getter_doc = field.occurrences.iter()
+            .find_map(|occurrence| occurrence.attributes.getter_doc.as_ref());
+        let doc_or = |default: &str| getter_doc.map_or_else(|| default.to_string(), syn::LitStr::value);
+        let doc = doc_or("Get the property of this enum discriminant if it's available");
+        let doc_mut = doc_or("Get the mutable property of this enum discriminant if it's available");
+        let doc_pin = doc_or("Get the pinned mutable property of this pinned enum discriminant if it's available");
+
+        methods.extend(quote! {
+            #[doc = #doc]
+            #deprecated
+            #must_use
+            #inline
+            #allow
+            #doc_hidden
+            #vis #constness fn #field_name_ident(&self) -> #ty #getter_where_clause {
+                match self {
+                    #variants
+                }
+            }
+        });
+
+        if !no_mut && pin {
+            methods.extend(quote! {
+                #[doc = #doc_pin]
+                #deprecated
+                #inline
+                #allow
+                #doc_hidden
+                #vis unsafe fn #field_name_ident_mut(self: ::std::pin::Pin<&mut Self>) -> #ty_mut {
+                    //!
+                    //! # Safety
+                    //! The field must be structurally pinned: it must not be moved out of
+                    //! the enum while the enum is pinned, e.g. by a setter or `Drop`, and
+                    //! the enum must only be `Unpin` when the field is.
+                    match unsafe { ::std::pin::Pin::get_unchecked_mut(self) } {
+                        #variants_mut
+                    }
+                }
+            });
+        } else if !no_mut {
+            methods.extend(quote! {
+                #[doc = #doc_mut]
+                #deprecated
+                #inline
+                #allow
+                #doc_hidden
+                #vis fn #field_name_ident_mut(&mut self) -> #ty_mut {
+                    match self {
+                        #variants_mut
+                    }
+                }
+            });
+
+            delegate_methods.extend(quote! {
+                #deprecated
+                #inline
+                #allow
+                #doc_hidden
+                #vis fn #field_name_ident_mut(&mut self) -> #ty_mut {
+                    //! Get the mutable property of the inner enum if it's available
+                    self.$member.#field_name_ident_mut()
+                }
+            });
+        }
+
+        delegate_methods.extend(quote! {
+            #deprecated
+            #must_use
+            #inline
+            #allow
+            #doc_hidden
+            #vis fn #field_name_ident(&self) -> #ty #getter_where_clause {
+                //! Get the property of the inner enum if it's available
+                self.$member.#field_name_ident()
+            }
+        });
+
+        // Getters with a restricted visibility are left out of the companion
+        // trait, as they would be exposed through it otherwise.
+        if is_visible_as_enum(vis, ast) {
+            trait_methods.extend(quote! {
+                #deprecated
+                #must_use
+                fn #field_name_ident(&self) -> #ty #getter_where_clause;
+            });
+
+            // Implementations of trait methods can't be deprecated, only the
+            // declarations can.
+            trait_impl_methods.extend(quote! {
+                #allow_deprecated
+                #inline
+                #allow
+                fn #field_name_ident(&self) -> #ty #getter_where_clause {
+                    #self_type::#field_name_ident(self)
+                }
+            });
+        }
+
+        let delegate = field.occurrences.iter()
+            .find(|occurrence| !occurrence.attributes.delegate.is_empty())
+            .map_or(&[][..], |occurrence| &occurrence.attributes.delegate);
+
+        for getter in delegate {
+            let delegate_ident = &getter.ident;
+            let delegate_name = delegate_ident.unraw().to_string();
+            method_names.claim(&delegate_name, format!("the delegate of field `{field_name}`"), delegate_ident.span())?;
+
+            let delegate_type = &getter.ty;
+            let ty_delegate = if field_direct {
+                quote! { #delegate_type }
+            } else {
+                quote! { Option<#delegate_type> }
+            };
+
+            let variants_delegate = getter_arms(&info, &field, |binding| {
+                quote! { #binding.#delegate_ident() }
+            });
+
+            methods.extend(quote! {
+                #deprecated
+                #must_use
+                #inline
+                #allow
+                #doc_hidden
+                #vis fn #delegate_ident(&self) -> #ty_delegate {
+                    //! Get the property of the inner value of this enum discriminant if it's available
+                    match self {
+                        #variants_delegate
+                    }
+                }
+            });
+        }
+
+        if attributes.str_accessors && is_string_type(field_type) {
+            let str_name = format!("{field_name}_str");
+            method_names.claim(&str_name, format!("field `{field_name}`"), field_span)?;
+
+            let str_ident = Ident::new(&str_name, Span::call_site());
+            let variants_str = getter_arms(&info, &field, |binding| {
+                quote! { #binding.as_str() }
+            });
+
+            let ty_str = if field_direct {
+                quote! { &str }
+            } else {
+                quote! { Option<&str> }
+            };
+
+            methods.extend(quote! {
+                #deprecated
+                #must_use
+                #inline
+                #allow
+                #doc_hidden
+                #vis fn #str_ident(&self) -> #ty_str {
+                    //! Get the property of this enum discriminant as a string slice if it's available
+                    match self {
+                        #variants_str
+                    }
+                }
+            });
+        }
+
+        if attributes.path_accessors && is_path_buf_type(field_type) {
+            let path_name = format!("{field_name}_path");
+            method_names.claim(&path_name, format!("field `{field_name}`"), field_span)?;
+
+            let path_ident = Ident::new(&path_name, Span::call_site());
+            let variants_path = getter_arms(&info, &field, |binding| {
+                quote! { #binding.as_path() }
+            });
+
+            let ty_path = if field_direct {
+                quote! { &::std::path::Path }
+            } else {
+                quote! { Option<&::std::path::Path> }
+            };
+
+            methods.extend(quote! {
+                #deprecated
+                #must_use
+                #inline
+                #allow
+                #doc_hidden
+                #vis fn #path_ident(&self) -> #ty_path {
+                    //! Get the property of this enum discriminant as a path slice if it's available
+                    match self {
+                        #variants_path
+                    }
+                }
+            });
+        }
+
+        if let Some(element_type) = vec_element_type(field_type).filter(|_| attributes.iter) {
+            let iter_name = format!("{field_name}_iter");
+            method_names.claim(&iter_name, format!("field `{field_name}`"), field_span)?;
+
+            let iter_ident = Ident::new(&iter_name, Span::call_site());
+            let variants_iter = getter_arms(&info, &field, |binding| {
+                quote! { #binding.iter() }
+            });
+
+            let ty_iter = if field_direct {
+                quote! { ::std::slice::Iter<'_, #element_type> }
+            } else {
+                quote! { Option<::std::slice::Iter<'_, #element_type>> }
+            };
+
+            methods.extend(quote! {
+                #deprecated
+                #must_use
+                #inline
+                #allow
+                #doc_hidden
+                #vis fn #iter_ident(&self) -> #ty_iter {
+                    //! Get an iterator over the elements of the property of this enum discriminant if it's available
+                    match self {
+                        #variants_iter
+                    }
+                }
+            });
+        }
+
+        if attributes.try_getters && !field_direct {
+            let try_name = format!("try_{field_name}");
+            method_names.claim(&try_name, format!("field `{field_name}`"), field_span)?;
+
+            let try_ident = Ident::new(&try_name, Span::call_site());
+            let variants_try = match_arms(&info, &field, |binding| {
+                quote! { Ok(#binding) }
+            }, |variant| {
+                // The wildcard arm of non-exhaustive enums can't name the variant.
+                let variant_name = variant.map_or_else(|| "<unknown>".to_string(), |variant| variant.ident.to_string());
+                let error = quote! {
+                    ::enum_fields::EnumFieldError {
+                        field: #field_name,
+                        variant: #variant_name,
+                    }
+                };
+
+                if attributes.getter_result.is_some() {
+                    quote! { Err(::std::convert::From::from(#error)) }
+                } else {
+                    quote! { Err(#error) }
+                }
+            });
+
+            // The conversion of a custom error type is required by the method,
+            // so the error points at the type if it's missing.
+            let (error_type, error_where_clause) = match &attributes.getter_result {
+                Some(error_type) => (quote! { #error_type }, quote_spanned! { error_type.span()=>
+                    where #error_type: ::std::convert::From<::enum_fields::EnumFieldError>
+                }),
+                None => (quote! { ::enum_fields::EnumFieldError }, quote! {}),
+            };
+
+            methods.extend(quote! {
+                #deprecated
+                #must_use
+                #inline
+                #allow
+                #doc_hidden
+                #[track_caller]
+                #vis fn #try_ident(&self) -> Result<& #field_type, #error_type> #error_where_clause {
+                    //! Get the property of this enum discriminant, or an error if it isn't available
+                    match self {
+                        #variants_try
+                    }
+                }
+            });
+        }
+
+        if attributes.map {
+            let map_name = format!("map_{field_name}");
+            method_names.claim(&map_name, format!("field `{field_name}`"), field_span)?;
+
+            let map_ident = Ident::new(&map_name, Span::call_site());
+            // Like the parameter of a setter, the function uses a hygienic
+            // span to avoid being shadowed by a field named `f`.
+            let function = Ident::new("f", Span::mixed_site());
+            let variants_map = getter_arms(&info, &field, |binding| {
+                quote! { #function(#binding) }
+            });
+
+            let ty_map = if field_direct {
+                quote! { __R }
+            } else {
+                quote! { Option<__R> }
+            };
+
+            methods.extend(quote! {
+                #deprecated
+                #must_use
+                #inline
+                #allow
+                #doc_hidden
+                #vis fn #map_ident<__F, __R>(&self, #function: __F) -> #ty_map
+                where
+                    __F: FnOnce(& #field_type) -> __R,
+                {
+                    //! Apply the function to the property of this enum discriminant if it's available
+                    match self {
+                        #variants_map
+                    }
+                }
+            });
+        }
+
+        if attributes.visit {
+            let visit_name = format!("for_each_{field_name}");
+            method_names.claim(&visit_name, format!("field `{field_name}`"), field_span)?;
+
+            let visit_ident = Ident::new(&visit_name, Span::call_site());
+            // See the mapping methods for the hygienic span of the function.
+            let function = Ident::new("f", Span::mixed_site());
+            let variants_visit = match_arms(&info, &field, |binding| {
+                quote! { #function(#binding) }
+            }, |_| quote! { () });
+
+            methods.extend(quote! {
+                #deprecated
+                #inline
+                #allow
+                #doc_hidden
+                #vis fn #visit_ident<__F>(&self, mut #function: __F)
+                where
+                    __F: FnMut(& #field_type),
+                {
+                    //! Call the function with the property of this enum discriminant if it's available
+                    match self {
+                        #variants_visit
+                    }
+                }
+            });
+        }
+
+        if attributes.compare {
+            let same_name = format!("same_{field_name}");
+            method_names.claim(&same_name, format!("field `{field_name}`"), field_span)?;
+
+            let same_ident = Ident::new(&same_name, Span::call_site());
+            let this = Ident::new("this", Span::mixed_site());
+            let other = Ident::new("other", Span::mixed_site());
+            let variants_same = getter_arms(&info, &field, |binding| {
+                quote! { #binding }
+            });
+
+            let comparison = if field_direct {
+                quote! { #this == #other }
+            } else {
+                quote! {
+                    match (#this, #other) {
+                        (Some(#this), Some(#other)) => #this == #other,
+                        _ => false,
+                    }
+                }
+            };
+
+            let partial_eq_bound = method_bound(field_type, quote! { PartialEq });
+
+            methods.extend(quote! {
+                #deprecated
+                #must_use
+                #inline
+                #allow
+                #doc_hidden
+                #vis fn #same_ident(&self, #other: &Self) -> bool
+                where
+                    #partial_eq_bound,
+                {
+                    //! Check whether both enums have this property and it is equal
+                    let #this = match self {
+                        #variants_same
+                    };
+                    let #other = match #other {
+                        #variants_same
+                    };
+                    #comparison
+                }
+            });
+        }
+
+        if attributes.eq_field.as_ref().is_some_and(|eq_field| eq_field.unraw() == field_name) {
+            if !field_present_everywhere {
+                return Err(syn::Error::new(field_span, format!(
+                    "`eq_field` can only be used with fields that are present in every variant, unlike `{field_name}`"
+                )));
+            }
+
+            eq_field_found = true;
+            let eq_name = format!("eq_by_{field_name}");
+            method_names.claim(&eq_name, format!("field `{field_name}`"), field_span)?;
+
+            let eq_ident = Ident::new(&eq_name, Span::call_site());
+            // See the comparing methods for the hygienic spans.
+            let this = Ident::new("this", Span::mixed_site());
+            let other = Ident::new("other", Span::mixed_site());
+            let variants_eq = match_arms(&info, &field, |binding| {
+                quote! { #binding }
+            }, |_| quote! { unreachable!() });
+
+            let partial_eq_bound = method_bound(field_type, quote! { PartialEq });
+
+            methods.extend(quote! {
+                #deprecated
+                #must_use
+                #inline
+                #allow
+                #doc_hidden
+                #vis fn #eq_ident(&self, #other: &Self) -> bool
+                where
+                    #partial_eq_bound,
+                {
+                    //! Check whether both enums are equal by this property, ignoring any other
+                    let #this = match self {
+                        #variants_eq
+                    };
+                    let #other = match #other {
+                        #variants_eq
+                    };
+                    #this == #other
+                }
+            });
+        }
+
+        if attributes.unwrap && !field_direct {
+            let unwrap_name = format!("unwrap_{field_name}");
+            method_names.claim(&unwrap_name, format!("field `{field_name}`"), field_span)?;
+
+            let unwrap_ident = Ident::new(&unwrap_name, Span::call_site());
+            let variants_unwrap = match_arms(&info, &field, |binding| {
+                quote! { #binding }
+            }, |variant| {
+                let message = match variant {
+                    Some(variant) => format!(
+                        "called `{unwrap_name}()` on the `{}` variant, which doesn't have the field `{field_name}`",
+                        variant.ident,
+                    ),
+                    None => format!("called `{unwrap_name}()` on a variant without the field `{field_name}`"),
+                };
+                quote! { panic!(#message) }
+            });
+
+            methods.extend(quote! {
+                #deprecated
+                #must_use
+                #inline
+                #allow
+                #doc_hidden
+                #[track_caller]
+                #vis fn #unwrap_ident(&self) -> & #field_type {
+                    //! Get the property of this enum discriminant, panicking if it isn't available
+                    match self {
+                        #variants_unwrap
+                    }
+                }
+            });
+        }
+
+        if attributes.or && !field_direct {
+            let or_name = format!("{field_name}_or");
+            method_names.claim(&or_name, format!("field `{field_name}`"), field_span)?;
+
+            let or_ident = Ident::new(&or_name, Span::call_site());
+            // See the setters for the hygienic span of the parameter.
+            let default = Ident::new("default", Span::mixed_site());
+            let variants_or = match_arms(&info, &field, |binding| {
+                quote! { #binding }
+            }, |_| quote! { #default });
+
+            methods.extend(quote! {
+                #deprecated
+                #must_use
+                #inline
+                #allow
+                #doc_hidden
+                #vis fn #or_ident<'__a>(&'__a self, #default: &'__a #field_type) -> &'__a #field_type {
+                    //! Get the property of this enum discriminant, or the default if it isn't available
+                    match self {
+                        #variants_or
+                    }
+                }
+            });
+        }
+
+        if field.occurrences.iter().any(|occurrence| occurrence.attributes.clone) {
+            let cloned_name = format!("{field_name}_cloned");
+            method_names.claim(&cloned_name, format!("field `{field_name}`"), field_span)?;
+
+            let cloned_ident = Ident::new(&cloned_name, Span::call_site());
+            let variants_cloned = getter_arms(&info, &field, |binding| {
+                quote! { ::std::clone::Clone::clone(#binding) }
+            });
+
+            let ty_cloned = if field_direct {
+                quote! { #field_type }
+            } else {
+                quote! { Option<#field_type> }
+            };
+
+            let clone_bound = quote_spanned! { field_type.span()=> #field_type: ::std::clone::Clone };
+
+            methods.extend(quote! {
+                #deprecated
+                #must_use
+                #inline
+                #allow
+                #doc_hidden
+                #vis fn #cloned_ident(&self) -> #ty_cloned
+                where
+                    #clone_bound,
+                {
+                    //! Get a clone of the property of this enum discriminant if it's available
+                    match self {
+                        #variants_cloned
+                    }
+                }
+            });
+        }
+
+        if field.occurrences.iter().any(|occurrence| occurrence.attributes.raw_ptr) {
+            let ptr_name = format!("{field_name}_ptr");
+            method_names.claim(&ptr_name, format!("field `{field_name}`"), field_span)?;
+
+            let ptr_ident = Ident::new(&ptr_name, Span::call_site());
+            let variants_ptr = match_arms(&info, &field, |binding| {
+                quote! { #binding as *const #field_type }
+            }, |_| quote! { ::std::ptr::null() });
+
+            methods.extend(quote! {
+                #deprecated
+                #must_use
+                #inline
+                #allow
+                #doc_hidden
+                #vis fn #ptr_ident(&self) -> *const #field_type {
+                    //! Get a pointer to the property of this enum discriminant, which is null if it isn't available
+                    match self {
+                        #variants_ptr
+                    }
+                }
+            });
+
+            // Like the mutable getter, the mutable pointer is null for the
+            // variants that don't allow mutating the field.
+            if !no_mut {
+                let ptr_name_mut = format!("{field_name}_ptr_mut");
+                method_names.claim(&ptr_name_mut, format!("field `{field_name}`"), field_span)?;
+
+                let ptr_ident_mut = Ident::new(&ptr_name_mut, Span::call_site());
+                let variants_ptr_mut = mutable_getter_arms(&info, &field, &no_mut_variants, |binding| {
+                    quote! { #binding as *mut #field_type }
+                });
+
+                methods.extend(quote! {
+                    #deprecated
+                    #inline
+                    #allow
+                    #doc_hidden
+                    #vis fn #ptr_ident_mut(&mut self) -> *mut #field_type {
+                        //! Get a mutable pointer to the property of this enum discriminant, which is null if it isn't available
+                        let ptr = match self {
+                            #variants_ptr_mut
+                        };
+                        ptr.unwrap_or_else(::std::ptr::null_mut)
+                    }
+                });
+            }
+        }
+
+        if field.occurrences.iter().any(|occurrence| occurrence.attributes.from) {
+            let from_name = format!("into_{field_name}_opt");
+            method_names.claim(&from_name, format!("field `{field_name}`"), field_span)?;
+
+            // Only the variants wrapping nothing but this field convert into
+            // it, as the other fields would be lost otherwise.
+            let other_variants: Vec<usize> = field.occurrences.iter()
+                .filter(|occurrence| info.data.variants[occurrence.variant].fields.len() != 1)
+                .map(|occurrence| occurrence.variant)
+                .collect();
+
+            if other_variants.len() == field.occurrences.len() {
+                return Err(syn::Error::new(
+                    field_span,
+                    format!("`from` requires a variant holding nothing but the field `{field_name}`"),
+                ));
+            }
+
+            let from_ident = Ident::new(&from_name, Span::call_site());
+            let variants_from = mutable_getter_arms(&info, &field, &other_variants, |binding| {
+                quote! { #binding }
+            });
+
+            methods.extend(quote! {
+                #deprecated
+                #must_use
+                #inline
+                #allow
+                #doc_hidden
+                #vis fn #from_ident(self) -> Option<#field_type> {
+                    //! Convert this enum into the property if the discriminant holds nothing else
+                    match self {
+                        #variants_from
+                    }
+                }
+            });
+        }
+
+        if attributes.into {
+            let into_name = format!("into_{field_name}");
+            method_names.claim(&into_name, format!("field `{field_name}`"), field_span)?;
+
+            let into_ident = Ident::new(&into_name, Span::call_site());
+            let variants_into = getter_arms(&info, &field, |binding| {
+                quote! { #binding }
+            });
+
+            let ty_into = if field_direct {
+                quote! { #field_type }
+            } else {
+                quote! { Option<#field_type> }
+            };
+
+            methods.extend(quote! {
+                #deprecated
+                #must_use
+                #inline
+                #allow
+                #doc_hidden
+                #vis fn #into_ident(self) -> #ty_into {
+                    //! Take the property out of this enum discriminant if it's available
+                    match self {
+                        #variants_into
+                    }
+                }
+            });
+        }
+
+        if attributes.setters && !readonly {
+            let setter_name = format!("set_{field_name}");
+            method_names.claim(&setter_name, format!("field `{field_name}`"), field_span)?;
+
+            let setter_ident = Ident::new(&setter_name, Span::call_site());
+            // The parameter uses a hygienic span, as the field binding could
+            // otherwise shadow it when the field itself is named `value`.
+            let value = Ident::new("value", Span::mixed_site());
+
+            if field_direct {
+                let variants_set = match_arms(&info, &field, |binding| {
+                    quote! { *#binding = #value }
+                }, |_| quote! { () });
+
+                methods.extend(quote! {
+                    #deprecated
+                    #inline
+                    #allow
+                    #doc_hidden
+                    #vis fn #setter_ident(&mut self, #value: #field_type) {
+                        //! Set the property of this enum discriminant
+                        match self {
+                            #variants_set
+                        }
+                    }
+                });
+            } else {
+                let variants_set = match_arms(&info, &field, |binding| {
+                    quote! {
+                        {
+                            *#binding = #value;
+                            true
+                        }
+                    }
+                }, |_| quote! { false });
+
+                methods.extend(quote! {
+                    #deprecated
+                    #inline
+                    #allow
+                    #doc_hidden
+                    #vis fn #setter_ident(&mut self, #value: #field_type) -> bool {
+                        //! Set the property of this enum discriminant if it's available, returning whether it was
+                        match self {
+                            #variants_set
+                        }
+                    }
+                });
+            }
+        }
+
+        if attributes.swap && !readonly {
+            let swap_name = format!("swap_{field_name}");
+            method_names.claim(&swap_name, format!("field `{field_name}`"), field_span)?;
+
+            let swap_ident = Ident::new(&swap_name, Span::call_site());
+            // Like the parameter of a setter, the other enum and the fields
+            // use hygienic spans, so field bindings can't shadow them.
+            let other = Ident::new("other", Span::mixed_site());
+            let value = Ident::new("value", Span::mixed_site());
+            let other_value = Ident::new("other_value", Span::mixed_site());
+            let variants_swap = getter_arms(&info, &field, |binding| {
+                quote! { #binding }
+            });
+
+            if field_direct {
+                methods.extend(quote! {
+                    #deprecated
+                    #inline
+                    #allow
+                    #doc_hidden
+                    #vis fn #swap_ident(&mut self, #other: &mut Self) {
+                        //! Swap the property of this enum discriminant with the one of the other
+                        let #value = match self {
+                            #variants_swap
+                        };
+                        let #other_value = match #other {
+                            #variants_swap
+                        };
+                        ::std::mem::swap(#value, #other_value);
+                    }
+                });
+            } else {
+                methods.extend(quote! {
+                    #deprecated
+                    #inline
+                    #allow
+                    #doc_hidden
+                    #vis fn #swap_ident(&mut self, #other: &mut Self) -> bool {
+                        //! Swap the property of this enum discriminant with the one of the other if both have it, returning whether they did
+                        let #value = match self {
+                            #variants_swap
+                        };
+                        let #other_value = match #other {
+                            #variants_swap
+                        };
+                        match (#value, #other_value) {
+                            (Some(#value), Some(#other_value)) => {
+                                ::std::mem::swap(#value, #other_value);
+                                true
+                            }
+                            _ => false,
+                        }
+                    }
+                });
+            }
+        }
+
+        if attributes.take && !readonly {
+            let take_name = format!("take_{field_name}");
+            method_names.claim(&take_name, format!("field `{field_name}`"), field_span)?;
+
+            let take_ident = Ident::new(&take_name, Span::call_site());
+            let variants_take = getter_arms(&info, &field, |binding| {
+                quote! { ::std::mem::take(#binding) }
+            });
+
+            let ty_take = if field_direct {
+                quote! { #field_type }
+            } else {
+                quote! { Option<#field_type> }
+            };
+
+            let default_bound = method_bound(field_type, quote! { ::std::default::Default });
+
+            methods.extend(quote! {
+                #deprecated
+                #inline
+                #allow
+                #doc_hidden
+                #vis fn #take_ident(&mut self) -> #ty_take
+                where
+                    #default_bound,
+                {
+                    //! Take the property out of this enum discriminant if it's available, leaving the default in its place
+                    match self {
+                        #variants_take
+                    }
+                }
+            });
+        }
+
+        if attributes.replace && !readonly {
+            let replace_name = format!("replace_{field_name}");
+            method_names.claim(&replace_name, format!("field `{field_name}`"), field_span)?;
+
+            let replace_ident = Ident::new(&replace_name, Span::call_site());
+            let value = Ident::new("value", Span::mixed_site());
+            let variants_replace = getter_arms(&info, &field, |binding| {
+                quote! { ::std::mem::replace(#binding, #value) }
+            });
+
+            let (ty_replace, doc) = if field_direct {
+                (quote! { #field_type }, "Replace the property of this enum discriminant, returning the previous value")
+            } else {
+                (quote! { Option<#field_type> }, "Replace the property of this enum discriminant if it's available, returning the previous value")
+            };
+
+            methods.extend(quote! {
+                #deprecated
+                #inline
+                #allow
+                #doc_hidden
+                #[doc = #doc]
+                #vis fn #replace_ident(&mut self, #value: #field_type) -> #ty_replace {
+                    match self {
+                        #variants_replace
+                    }
+                }
+            });
+        }
+
+        if attributes.with && !readonly {
+            let with_name = format!("with_{field_name}");
+            method_names.claim(&with_name, format!("field `{field_name}`"), field_span)?;
+
+            let with_ident = Ident::new(&with_name, Span::call_site());
+            // See the setters for the hygienic span of the parameter.
+            let value = Ident::new("value", Span::mixed_site());
+            let variants_with = match_arms(&info, &field, |binding| {
+                quote! { *#binding = #value }
+            }, |_| quote! { () });
+
+            methods.extend(quote! {
+                #deprecated
+                #inline
+                #allow
+                #doc_hidden
+                #vis fn #with_ident(mut self, #value: #field_type) -> Self {
+                    //! Set the property of this enum discriminant if it's available, and return the enum
+                    match &mut self {
+                        #variants_with
+                    }
+                    self
+                }
+            });
+        }
+
+        if attributes.shared_key && field_present_everywhere {
+            let variants_key = match_arms(&info, &field, |binding| {
+                quote! { #binding }
+            }, |_| quote! { unreachable!() });
+
+            shared_key_fields.push((field_type, variants_key));
+        }
+
+        if attributes.split_mut && field_present_everywhere && !no_mut && no_mut_variants.is_empty() {
+            let mut bindings = vec![None; info.data.variants.len()];
+            for occurrence in &field.occurrences {
+                let binding = field.arms[occurrence.variant].as_ref().map(|arm| &arm.binding);
+                let Some(binding) = binding else {
+                    continue;
+                };
+
+                let pattern = match &occurrence.member {
+                    syn::Member::Named(ident) => quote! { #ident },
+                    syn::Member::Unnamed(index) => quote! { #index: #binding },
+                };
+                bindings[occurrence.variant] = Some((pattern, binding.clone()));
+            }
+
+            split_mut_fields.push((field_type, bindings));
+        }
+
+        if attributes.transparent && field_present_everywhere {
+            let name = &ast.ident;
+            let generics = impl_generics(ast, &attributes);
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+            let variants_deref = match_arms(&info, &field, |binding| {
+                quote! { #binding }
+            }, |_| quote! { unreachable!() });
+
+            deref_impl = Some(quote! {
+                #[automatically_derived]
+                impl #impl_generics ::std::ops::Deref for #name #ty_generics #where_clause {
+                    type Target = #field_type;
+
+                    #allow_deprecated
+                    #inline
+                    #allow
+                    fn deref(&self) -> &Self::Target {
+                        match self {
+                            #variants_deref
+                        }
+                    }
+                }
+            });
+        }
+
+        let explicit_as_ref = field.occurrences.iter().any(|occurrence| occurrence.attributes.as_ref);
+        if explicit_as_ref && !field_present_everywhere {
+            return Err(syn::Error::new(field_span, "`as_ref` can only be used on fields that are present in every variant"));
+        }
+
+        if explicit_as_ref || (attributes.as_ref && field_present_everywhere) {
+            let name = &ast.ident;
+            let generics = impl_generics(ast, &attributes);
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+            let variants_as_ref = match_arms(&info, &field, |binding| {
+                quote! { #binding }
+            }, |_| quote! { unreachable!() });
+
+            as_ref_impls.push((field_name.clone(), explicit_as_ref, quote! {
+                #[automatically_derived]
+                impl #impl_generics ::std::convert::AsRef<#field_type> for #name #ty_generics #where_clause {
+                    #allow_deprecated
+                    #inline
+                    #allow
+                    fn as_ref(&self) -> & #field_type {
+                        match self {
+                            #variants_as_ref
+                        }
+                    }
+                }
+            }));
+        }
+    }
+
+    if let Some(eq_field) = attributes.eq_field.as_ref().filter(|_| !eq_field_found) {
+        return Err(syn::Error::new(eq_field.span(), format!(
+            "`eq_field` names the field `{}`, which none of the variants have", eq_field.unraw(),
+        )));
+    }
+
+    if attributes.shared_key {
+        method_names.claim("shared_key", "the shared key".into(), Span::call_site())?;
+
+        // Like the variant getters, a single field is returned directly
+        // instead of wrapped in a tuple.
+        let (ty, value) = match shared_key_fields.as_slice() {
+            [] => return Err(syn::Error::new(Span::call_site(),
+                "`shared_key` requires a field that is present in every variant",
+            )),
+            [(ty, variants)] => (quote! { &#ty }, quote! { match self { #variants } }),
+            _ => {
+                let types = shared_key_fields.iter().map(|(ty, _)| ty);
+                let values = shared_key_fields.iter().map(|(_, variants)| quote! { match self { #variants } });
+                (quote! { (#(&#types),*) }, quote! { (#(#values),*) })
+            }
+        };
+
+        methods.extend(quote! {
+            #must_use
+            #inline
+            #allow
+            #doc_hidden
+            #vis fn shared_key(&self) -> #ty {
+                //! Get the properties present in every enum discriminant, in declaration order
+                #value
+            }
+        });
+    }
+
+    if attributes.split_mut {
+        method_names.claim("fields_mut", "the split mutable fields".into(), Span::call_site())?;
+
+        if split_mut_fields.is_empty() {
+            return Err(syn::Error::new(Span::call_site(),
+                "`split_mut` requires a mutable field that is present in every variant",
+            ));
+        }
+
+        // Every field is bound by the same arm, which is what allows the
+        // mutable references to be returned at the same time.
+        let mut variants_split = proc_macro2::TokenStream::new();
+        for (variant_index, variant) in enum_data.variants.iter().enumerate() {
+            let cfg = &info.cfgs[variant_index];
+            let variant_name = &variant.ident;
+
+            // The fields are present in every variant, so each of them is bound.
+            let bindings: Vec<_> = split_mut_fields.iter()
+                .filter_map(|(_, bindings)| bindings[variant_index].as_ref())
+                .collect();
+            let patterns = bindings.iter().map(|(pattern, _)| pattern);
+            let bindings = bindings.iter().map(|(_, binding)| binding);
+
+            variants_split.extend(quote! {
+                #cfg
+                Self::#variant_name{ #(#patterns,)* .. } => (#(#bindings),*),
+            });
+        }
+
+        // Like the shared key, a single field is returned directly, as neither
+        // `(&mut T)` nor `(value)` is a tuple.
+        let types = split_mut_fields.iter().map(|(ty, _)| ty);
+        let ty = quote! { (#(&mut #types),*) };
+
+        methods.extend(quote! {
+            #inline
+            #allow
+            #doc_hidden
+            #vis fn fields_mut(&mut self) -> #ty {
+                //! Get the mutable properties present in every enum discriminant at once, in declaration order
+                match self {
+                    #variants_split
+                }
+            }
+        });
+    }
+
+    let mut output = impl_blocks(ast, &attributes, methods, trait_methods, trait_impl_methods)?;
+
+    // Wrappers can only forward to enums without generics, as the wrapper
+    // would otherwise need the same generics.
+    if ast.generics.params.is_empty() {
+        let delegate_macro = delegate_macro_ident(&ast.ident);
+        output.extend(quote! {
+            #[doc(hidden)]
+            #[allow(unused_macros)]
+            macro_rules! #delegate_macro {
+                ($wrapper:ident, $member:tt) => {
+                    impl $wrapper {
+                        #delegate_methods
+                    }
+                };
+            }
+        });
+    }
+
+    // Fields annotated with `as_ref` take precedence over the annotation on the
+    // enum, which is only unambiguous for a single field.
+    if as_ref_impls.iter().any(|(_, explicit, _)| *explicit) {
+        as_ref_impls.retain(|(_, explicit, _)| *explicit);
+    } else if attributes.as_ref {
+        match as_ref_impls.as_slice() {
+            [] => return Err(syn::Error::new(Span::call_site(),
+                "`as_ref` requires a field that is present in every variant",
+            )),
+            [_] => (),
+            _ => {
+                let names = as_ref_impls.iter()
+                    .map(|(name, _, _)| format!("`{name}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                return Err(syn::Error::new(Span::call_site(), format!(
+                    "`as_ref` is ambiguous, as the fields {names} are present in every variant; \
+                    consider annotating one of them with `#[enum_fields(as_ref)]` instead"
+                )));
+            }
+        }
+    }
+
+    output.extend(as_ref_impls.into_iter().map(|(_, _, as_ref_impl)| as_ref_impl));
+
+    if attributes.transparent {
+        let Some(deref_impl) = deref_impl else {
+            return Err(syn::Error::new(Span::call_site(),
+                "`transparent` requires the field of every variant to have the same name and type",
+            ));
+        };
+        output.extend(deref_impl);
+    }
+
+    Ok(output)
+}
+
+/// Returns the name of the `macro_rules!` generated alongside the methods of the
+/// enum, which generates the getters of a wrapper around it.
+fn delegate_macro_ident(enum_ident: &Ident) -> Ident {
+    Ident::new(&format!("__enum_fields_delegate_{}", enum_ident.unraw()), enum_ident.span())
+}
+
+/// Returns the generics of the generated `impl`s, which are the generics of the
+/// enum along with the bounds of the user.
+fn impl_generics(ast: &syn::DeriveInput, attributes: &EnumAttributes) -> syn::Generics {
+    let mut generics = ast.generics.clone();
+    if !attributes.bound.is_empty() {
+        generics.make_where_clause().predicates.extend(attributes.bound.iter().cloned());
+    }
+    generics
+}
+
+/// Whether a getter with the given visibility is as visible as the enum, so it
+/// can be part of the companion trait. The trait has the visibility of the
+/// enum, so it would expose the getters with a more restricted one otherwise.
+fn is_visible_as_enum(vis: &syn::Visibility, ast: &syn::DeriveInput) -> bool {
+    matches!(vis, syn::Visibility::Public(_))
+        || vis.to_token_stream().to_string() == ast.vis.to_token_stream().to_string()
+}
+
+/// Returns the type of the enum or union in the generated code, e.g.
+/// `<Tree<T>>`. The implementations of the companion trait call the getters
+/// through it instead of `Self`, so they can be shared by the implementation
+/// for `Box<Self>`, which forwards to them by deref coercion.
+fn self_type(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    let name = &ast.ident;
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+    quote! { <#name #ty_generics> }
+}
+
+/// Creates the `impl` block with the generated methods, along with the
+/// companion trait and its implementations if requested.
+fn impl_blocks(
+    ast: &syn::DeriveInput,
+    attributes: &EnumAttributes,
+    methods: proc_macro2::TokenStream,
+    trait_methods: proc_macro2::TokenStream,
+    trait_impl_methods: proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &ast.ident;
+    let generics = impl_generics(ast, attributes);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut output = quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #methods
+        }
+    };
+
+    // With the `trait-accessors` feature, every enum gets a companion trait,
+    // named after the enum unless a name is specified.
+    let trait_name = attributes.trait_name.clone().or_else(|| {
+        cfg!(feature = "trait-accessors").then(|| Ident::new(&format!("{}Fields", name.unraw()), name.span()))
+    });
+
+    if let Some(trait_name) = &trait_name {
+        let trait_vis = &ast.vis;
+        let doc = format!("The field getters of [`{name}`], so they can be used generically.");
+
+        output.extend(quote! {
+            #[doc = #doc]
+            #trait_vis trait #trait_name #generics #where_clause {
+                #trait_methods
+            }
+
+            #[automatically_derived]
+            impl #impl_generics #trait_name #ty_generics for #name #ty_generics #where_clause {
+                #trait_impl_methods
+            }
+        });
+    }
+
+    if attributes.impl_for_box {
+        let Some(trait_name) = &trait_name else {
+            return Err(syn::Error::new(Span::call_site(),
+                "`impl_for_box` requires a companion trait, e.g. using `#[enum_fields(trait_name = \"...\")]`",
+            ));
+        };
+
+        output.extend(quote! {
+            #[automatically_derived]
+            impl #impl_generics #trait_name #ty_generics for ::std::boxed::Box<#name #ty_generics> #where_clause {
+                #trait_impl_methods
+            }
+        });
+    }
+
+    Ok(output)
+}
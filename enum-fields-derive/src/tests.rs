@@ -0,0 +1,157 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! Tests of the generated code itself, for the properties of it that neither
+//! the examples nor the compile errors of the `enum-fields` crate can observe.
+
+/// Expands the derive for the enum `input`, as a string of its tokens.
+fn expand(input: proc_macro2::TokenStream) -> String {
+    let ast: syn::DeriveInput = syn::parse2(input).unwrap();
+    let syn::Data::Enum(enum_data) = &ast.data else {
+        panic!("the input must be an enum");
+    };
+
+    crate::impl_for_enum(&ast, enum_data).unwrap().to_string()
+}
+
+/// The attributes of every `pub fn` in the `expanded` code, along with the name
+/// of the method. The attributes are the tokens between the end of the
+/// previous item and the method, which is enough for the generated code.
+fn method_attributes(expanded: &str) -> Vec<(&str, &str)> {
+    expanded.match_indices("pub fn ")
+        .map(|(index, _)| {
+            let start = expanded[..index].rfind(['{', '}', ';']).map_or(0, |end| end + 1);
+            let name = expanded[index..].split_whitespace().nth(2).unwrap();
+            (name, expanded[start..index].trim())
+        })
+        .collect()
+}
+
+#[test]
+fn methods_are_in_order_of_first_appearance() {
+    let expanded = expand(quote::quote! {
+        pub enum Entity {
+            Company { zeta: u8, alpha: u8 },
+            Person { middle: u8, alpha: u8, zeta: u8 },
+        }
+    });
+
+    let getters: Vec<usize> = ["zeta", "alpha", "middle"].iter()
+        .map(|field| expanded.find(&format!("pub fn {field} (")).unwrap())
+        .collect();
+    assert!(getters.windows(2).all(|pair| pair[0] < pair[1]), "{expanded}");
+}
+
+#[test]
+fn methods_are_inline() {
+    let expanded = expand(quote::quote! {
+        #[enum_fields(as_variant, compare, into, map, or, replace, setters, shared_key, swap, take, try, unwrap, visit, with)]
+        pub enum Entity {
+            Company { name: String, ceo: String },
+            Person { name: String },
+        }
+    });
+
+    let methods = method_attributes(&expanded);
+    assert!(methods.len() > 30, "{expanded}");
+    for (name, attributes) in methods {
+        assert!(attributes.contains("# [inline]"), "`{name}` isn't inline: {attributes}");
+    }
+
+    let expanded = expand(quote::quote! {
+        #[enum_fields(inline(always), trait_name = "EntityFields")]
+        pub enum Entity {
+            Company { name: String, ceo: String },
+            Person { name: String },
+        }
+    });
+
+    for (name, attributes) in method_attributes(&expanded) {
+        assert!(attributes.contains("# [inline (always)]"), "`{name}` isn't always inline: {attributes}");
+    }
+    assert!(expanded.contains("# [inline (always)] fn name (& self)"), "{expanded}");
+}
+
+#[test]
+fn methods_are_hidden_with_doc_hidden() {
+    let input = |attributes| quote::quote! {
+        #[enum_fields(#attributes as_variant, setters, take, try, with)]
+        pub enum Entity {
+            Company { name: String, ceo: String },
+            Person { name: String },
+        }
+    };
+
+    let expanded = expand(input(quote::quote! { doc_hidden, }));
+    for (name, attributes) in method_attributes(&expanded) {
+        assert!(attributes.contains("# [doc (hidden)]"), "`{name}` isn't hidden: {attributes}");
+    }
+    assert!(expanded.contains("# [doc (hidden)] pub const VARIANTS"), "{expanded}");
+
+    let expanded = expand(input(quote::quote! {}));
+    for (name, attributes) in method_attributes(&expanded) {
+        assert!(!attributes.contains("# [doc (hidden)]"), "`{name}` is hidden: {attributes}");
+    }
+}
+
+#[test]
+fn trait_implementations_are_automatically_derived() {
+    let expanded = [
+        quote::quote! {
+            #[enum_fields(as_ref, trait_name = "EntityFields", impl_for_box)]
+            pub enum Entity {
+                Company { name: String, ceo: String },
+                Person { name: String },
+            }
+        },
+        quote::quote! {
+            #[enum_fields(transparent)]
+            pub enum Name {
+                Full(String),
+                Nickname(String),
+            }
+        },
+    ].map(expand).join(" ");
+
+    let implementations: Vec<(&str, &str)> = expanded.match_indices("impl ")
+        .filter(|(index, _)| *index == 0 || expanded[..*index].ends_with(' '))
+        .map(|(index, _)| {
+            let header = &expanded[index..];
+            (&expanded[..index], &header[..header.find('{').unwrap()])
+        })
+        .collect();
+
+    let trait_implementations = implementations.iter().filter(|(_, header)| header.contains(" for ")).count();
+    assert!(trait_implementations >= 4, "{expanded}");
+
+    for (before, header) in implementations {
+        let automatically_derived = before.trim_end().ends_with("# [automatically_derived]");
+        assert_eq!(automatically_derived, header.contains(" for "), "{header}");
+    }
+}
+
+#[test]
+fn getter_doc_replaces_the_documentation_of_the_getters() {
+    let expanded = expand(quote::quote! {
+        pub enum Entity {
+            Company {
+                name: String,
+
+                /// The chief executive officer of the company.
+                #[enum_fields(getter_doc = "The CEO of the company, if any.")]
+                ceo: String,
+            },
+            Person { name: String },
+        }
+    });
+
+    let methods = method_attributes(&expanded);
+    for getter in ["ceo", "ceo_mut"] {
+        let (_, attributes) = methods.iter().find(|(name, _)| *name == getter).unwrap();
+        assert_eq!(attributes.matches("# [doc").count(), 1, "{attributes}");
+        assert!(attributes.contains("# [doc = \"The CEO of the company, if any.\"]"), "{attributes}");
+    }
+
+    let (_, attributes) = methods.iter().find(|(name, _)| *name == "name").unwrap();
+    assert!(attributes.contains("# [doc = \"Get the property of this enum discriminant if it's available\"]"), "{attributes}");
+}
@@ -0,0 +1,35 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases allowing lints on the generated methods.
+//!
+//! The enum [`Shape`] is annotated with
+//! `#[enum_fields(allow = "clippy::must_use_candidate, clippy::wrong_self_convention")]`,
+//! so those lints are allowed on `Shape::radius()`, `Shape::is_circle()` and
+//! the other generated methods, while they're still denied for the rest of
+//! the crate.
+
+#![deny(clippy::must_use_candidate, clippy::wrong_self_convention)]
+
+/// A shape of which the size is determined by its radius.
+#[derive(Clone, Copy, Debug, PartialEq, enum_fields::EnumFields)]
+#[enum_fields(allow = "clippy::must_use_candidate, clippy::wrong_self_convention")]
+pub enum Shape {
+    Circle {
+        radius: f64,
+    },
+
+    Hexagon {
+        radius: f64,
+        rotation: f64,
+    },
+}
+
+fn main() {
+    let circle = Shape::Circle { radius: 1.5 };
+    let hexagon = Shape::Hexagon { radius: 2.0, rotation: 0.5 };
+
+    assert!(circle.is_circle());
+    assert_eq!(circle.radius(), &1.5);
+    assert_eq!(hexagon.rotation(), Some(&0.5));
+}
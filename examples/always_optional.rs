@@ -0,0 +1,66 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`], which is annotated with
+//! `#[enum_fields(always_optional)]`.
+//!
+//! Even though `name` is present in every variant, `Entity::name()` returns
+//! an `Option<&String>`, just like `Entity::ceo()` does. Code using these
+//! getters therefore doesn't break when a variant without a `name` is added.
+//!
+//! Only the getters are affected: the methods and implementations requiring a
+//! field that is present in every variant, like `Entity::eq_by_name()`,
+//! `Entity::shared_key()` and the `AsRef<String>` implementation, are still
+//! generated for `name`. The [`Name`] enum is [`Deref`](std::ops::Deref) to
+//! the `String` it wraps as well.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(always_optional, as_ref, eq_field = "name", shared_key)]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+/// A name, either a full name or a nickname.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(always_optional, transparent)]
+pub enum Name {
+    Full(String),
+    Nickname(String),
+}
+
+fn main() {
+    let mut company = Entity::Company {
+        name: "Apple".into(),
+        ceo: "Tim Cook".into(),
+    };
+
+    let person = Entity::Person {
+        name: "Tim Berners-Lee".into(),
+    };
+
+    assert_eq!(company.name(), Some(&"Apple".into()));
+    assert_eq!(person.name(), Some(&"Tim Berners-Lee".into()));
+    assert_eq!(person.ceo(), None);
+
+    if let Some(name) = company.name_mut() {
+        name.push_str(" Inc.");
+    }
+    assert_eq!(company.name(), Some(&"Apple Inc.".into()));
+
+    let renamed = Entity::Person { name: "Apple Inc.".into() };
+    assert!(company.eq_by_name(&renamed));
+    assert_eq!(company.shared_key(), "Apple Inc.");
+    assert_eq!(AsRef::<String>::as_ref(&person), "Tim Berners-Lee");
+
+    let nickname = Name::Nickname("Tim".into());
+    assert_eq!(nickname.field_0(), Some(&"Tim".into()));
+    assert_eq!(nickname.len(), 3);
+}
@@ -0,0 +1,55 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases implementing [`AsRef`] for the field that
+//! is present in every variant.
+//!
+//! The enum [`Path`] is annotated with `#[enum_fields(as_ref)]`, and `text` is
+//! the only field present in every variant, so `Path` implements
+//! `AsRef<String>`. [`Document`] has two such fields, so the `body` field is
+//! annotated with `#[enum_fields(as_ref)]` instead to pick it.
+
+/// A path, either absolute or relative to a directory.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(as_ref)]
+pub enum Path {
+    Absolute {
+        text: String,
+    },
+
+    Relative {
+        text: String,
+        depth: usize,
+    },
+}
+
+/// A document, of which the body can be used as a string.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Document {
+    Plain {
+        title: String,
+
+        #[enum_fields(as_ref)]
+        body: String,
+    },
+
+    Markdown {
+        title: String,
+        body: String,
+    },
+}
+
+/// Returns the length of anything that can be used as a string.
+fn length(text: impl AsRef<String>) -> usize {
+    text.as_ref().len()
+}
+
+fn main() {
+    let path = Path::Relative { text: "../src".into(), depth: 1 };
+    assert_eq!(path.as_ref(), "../src");
+    assert_eq!(length(&path), 6);
+
+    let document = Document::Markdown { title: "Notes".into(), body: "# Notes".into() };
+    assert_eq!(AsRef::<String>::as_ref(&document), document.body());
+    assert_eq!(length(document), 7);
+}
@@ -0,0 +1,47 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Shape`], annotated with
+//! `#[enum_fields(as_variant)]`.
+//!
+//! For every variant, a getter is generated returning all of its fields when
+//! the enum is that variant, e.g. `Shape::as_rectangle()` returning an
+//! `Option<(&u32, &u32)>`, and `Shape::as_rectangle_mut()` returning an
+//! `Option<(&mut u32, &mut u32)>`. Variants with a single field return the
+//! field itself, and unit variants an `Option<()>`.
+
+/// A shape that can be drawn on a canvas.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(as_variant)]
+pub enum Shape {
+    Rectangle {
+        width: u32,
+        height: u32,
+    },
+
+    Circle {
+        radius: u32,
+    },
+
+    Polygon(Vec<(i32, i32)>, bool),
+
+    Empty,
+}
+
+fn main() {
+    let mut rectangle = Shape::Rectangle { width: 3, height: 4 };
+    let circle = Shape::Circle { radius: 5 };
+    let polygon = Shape::Polygon(vec![(0, 0), (1, 1), (1, 0)], true);
+
+    assert_eq!(rectangle.as_rectangle(), Some((&3, &4)));
+    assert_eq!(rectangle.as_circle(), None);
+    assert_eq!(circle.as_circle(), Some(&5));
+    assert_eq!(polygon.as_polygon(), Some((&vec![(0, 0), (1, 1), (1, 0)], &true)));
+    assert_eq!(Shape::Empty.as_empty(), Some(()));
+    assert_eq!(circle.as_empty(), None);
+
+    if let Some((width, height)) = rectangle.as_rectangle_mut() {
+        std::mem::swap(width, height);
+    }
+    assert_eq!(rectangle, Shape::Rectangle { width: 4, height: 3 });
+}
@@ -0,0 +1,51 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases a generic enum [`Layer`], which is
+//! annotated with `#[enum_fields(bound = "S: Shape")]`.
+//!
+//! The `shape` field delegates `Layer::area()` to [`Shape::area()`], which is
+//! only available if `S` implements [`Shape`], so that bound is added to the
+//! where-clause of the generated `impl`.
+
+/// A shape of which the area can be calculated.
+pub trait Shape {
+    fn area(&self) -> f64;
+}
+
+/// A square with sides of the given length.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Square(f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+}
+
+/// A layer of a drawing, containing a shape.
+#[derive(Clone, Debug, PartialEq, enum_fields::EnumFields)]
+#[enum_fields(bound = "S: Shape")]
+pub enum Layer<S> {
+    Fill {
+        #[enum_fields(delegate(area: f64))]
+        shape: S,
+        color: u32,
+    },
+
+    Outline {
+        #[enum_fields(delegate(area: f64))]
+        shape: S,
+        width: f64,
+    },
+}
+
+fn main() {
+    let fill = Layer::Fill { shape: Square(3.0), color: 0xFF0000 };
+    let outline = Layer::Outline { shape: Square(2.0), width: 0.5 };
+
+    assert_eq!(fill.area(), 9.0);
+    assert_eq!(outline.area(), 4.0);
+    assert_eq!(fill.shape(), &Square(3.0));
+    assert_eq!(outline.width(), Some(&0.5));
+}
@@ -0,0 +1,89 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Shape`], of which every variant
+//! has an `id` field.
+//!
+//! Since `id` is annotated with `#[enum_fields(by_value)]`, `Shape::id()`
+//! returns a `u64` instead of a `&u64`. The same goes for the optional
+//! `Shape::radius()`, which returns an `Option<f32>`.
+//!
+//! The mutable getters still return references, so `Shape::id_mut()` returns
+//! `&mut u64`.
+//!
+//! These getters are small enough to always be inlined, which is requested
+//! using `#[enum_fields(inline(always))]`.
+//!
+//! The generic [`Tagged`] returns its `tag` by value as well, which requires
+//! `T: Copy`. That bound only applies to `Tagged::tag()`, so a `Tagged<String>`
+//! can be used too, as long as it doesn't call that getter.
+
+/// A shape that can be drawn on a canvas.
+#[derive(Clone, Debug, PartialEq, enum_fields::EnumFields)]
+#[enum_fields(inline(always))]
+pub enum Shape {
+    Circle {
+        #[enum_fields(by_value)]
+        id: u64,
+
+        #[enum_fields(by_value)]
+        radius: f32,
+    },
+
+    Square {
+        #[enum_fields(by_value)]
+        id: u64,
+
+        size: f32,
+    },
+}
+
+/// A value with a tag of any type.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Tagged<T> {
+    Number {
+        #[enum_fields(by_value)]
+        tag: T,
+
+        count: i64,
+    },
+
+    Text {
+        #[enum_fields(by_value)]
+        tag: T,
+
+        content: String,
+    },
+}
+
+fn main() {
+    let mut circle = Shape::Circle { id: 1, radius: 2.5 };
+    let square = Shape::Square { id: 2, size: 4.0 };
+
+    assert_eq!(circle.id(), 1);
+    assert_eq!(square.id(), 2);
+
+    assert_eq!(circle.radius(), Some(2.5));
+    assert_eq!(square.radius(), None);
+
+    // Fields without the attribute are still returned by reference.
+    assert_eq!(square.size(), Some(&4.0));
+
+    *circle.id_mut() += 10;
+    assert_eq!(circle.id(), 11);
+
+    if let Some(radius) = circle.radius_mut() {
+        *radius *= 2.0;
+    }
+    assert_eq!(circle.radius(), Some(5.0));
+
+    let number = Tagged::Number { tag: 7_u32, count: -1 };
+    let tag: u32 = number.tag();
+    assert_eq!(tag, 7);
+
+    // `String` isn't `Copy`, so this can't call `Tagged::tag()`.
+    let mut text = Tagged::Text { tag: String::from("greeting"), content: "hi".into() };
+    text.tag_mut().push('!');
+    assert!(text.is_text());
+    assert_eq!(text, Tagged::Text { tag: "greeting!".into(), content: "hi".into() });
+}
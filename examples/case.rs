@@ -0,0 +1,54 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases how the names of variants are converted
+//! for the names of the methods generated for them.
+//!
+//! By default, they are converted to `snake_case`, so the predicate of the
+//! `CompanyName` variant of [`Entity`] is `Entity::is_company_name()`. The
+//! enum [`Status`] is annotated with `#[enum_fields(case = "verbatim")]`, so
+//! its predicates keep the names of the variants: `Status::is_HTTPError()`.
+//! Using `#[enum_fields(case = "camel")]`, the names are in `lowerCamelCase`
+//! instead, as for [`Token`]: `Token::is_openParen()`.
+
+/// An entity, named either by a company or a person.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Entity {
+    CompanyName(String),
+    PersonName(String),
+}
+
+/// The status of a request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(case = "verbatim")]
+pub enum Status {
+    Ok,
+    HTTPError {
+        code: u16,
+    },
+}
+
+/// A token of an expression.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(case = "camel", as_variant)]
+pub enum Token {
+    OpenParen,
+    CloseParen,
+    IntegerLiteral(i64),
+}
+
+fn main() {
+    let company = Entity::CompanyName("Apple".into());
+    assert!(company.is_company_name());
+    assert!(!company.is_person_name());
+
+    let status = Status::HTTPError { code: 404 };
+    assert!(status.is_HTTPError());
+    assert!(Status::Ok.is_Ok());
+    assert_eq!(status.code(), Some(&404));
+
+    let token = Token::IntegerLiteral(42);
+    assert!(Token::OpenParen.is_openParen());
+    assert!(!token.is_closeParen());
+    assert_eq!(token.as_integerLiteral(), Some(&42));
+}
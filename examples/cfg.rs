@@ -0,0 +1,52 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Backend`] with variants that are
+//! only available in some configurations.
+//!
+//! The `#[cfg(...)]` attributes of the variants are forwarded to the arms and
+//! methods generated for them, so `Backend::is_mock()` only exists when the
+//! `Mock` variant does. The `Disabled` variant is never compiled, since
+//! `cfg(any())` never holds.
+
+/// The backend used to store data.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(as_variant)]
+pub enum Backend {
+    Memory {
+        capacity: usize,
+    },
+
+    #[cfg(debug_assertions)]
+    Mock {
+        capacity: usize,
+        calls: Vec<String>,
+    },
+
+    #[cfg(any())]
+    Disabled {
+        reason: String,
+    },
+
+    File(String),
+}
+
+fn main() {
+    let memory = Backend::Memory { capacity: 16 };
+    assert_eq!(memory.capacity(), Some(&16));
+    assert_eq!(memory.field_0(), None);
+
+    let file = Backend::File("data.bin".into());
+    assert_eq!(file.variant_name(), "File");
+    assert!(!Backend::VARIANTS.contains(&"Disabled"));
+
+    #[cfg(debug_assertions)]
+    {
+        let mock = Backend::Mock { capacity: 4, calls: Vec::new() };
+        assert!(mock.is_mock());
+        assert_eq!(mock.capacity(), Some(&4));
+        assert_eq!(mock.field_names(), ["capacity", "calls"]);
+    }
+
+    assert_eq!(memory.field_names(), ["capacity"]);
+}
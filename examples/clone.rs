@@ -0,0 +1,45 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`] of which the fields are
+//! annotated with `#[enum_fields(clone)]`.
+//!
+//! This generates getters returning owned clones of the fields, so
+//! `Entity::name_cloned()` returns a `String`, and `Entity::ceo_cloned()` an
+//! `Option<String>`, while the entity can still be changed afterwards.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Entity {
+    Company {
+        #[enum_fields(clone)]
+        name: String,
+
+        #[enum_fields(clone)]
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+fn main() {
+    let mut company = Entity::Company {
+        name: "Apple".into(),
+        ceo: "Tim Cook".into(),
+    };
+
+    let person = Entity::Person {
+        name: "Tim Berners-Lee".into(),
+    };
+
+    let name: String = company.name_cloned();
+    *company.name_mut() = "Microsoft".into();
+    assert_eq!(name, "Apple");
+    assert_eq!(company.name(), "Microsoft");
+
+    assert_eq!(company.ceo_cloned(), Some("Tim Cook".into()));
+    assert_eq!(person.ceo_cloned(), None);
+    assert_eq!(person.name_cloned(), "Tim Berners-Lee");
+}
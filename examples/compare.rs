@@ -0,0 +1,73 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`] annotated with
+//! `#[enum_fields(compare)]`.
+//!
+//! For every field, a method is generated comparing that field of two
+//! entities, e.g. `Entity::same_name()`. Since only a `Company` has a `ceo`,
+//! `Entity::same_ceo()` returns `false` when either entity is a `Person`.
+//!
+//! The enum [`Button`] has a field of which the type doesn't implement
+//! [`PartialEq`]. The other fields can still be compared.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, enum_fields::EnumFields)]
+#[enum_fields(compare)]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+/// A button with an action that is run when it is clicked.
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(compare)]
+pub enum Button {
+    Text {
+        label: String,
+        on_click: Box<dyn Fn() -> u32>,
+    },
+}
+
+fn main() {
+    let apple = Entity::Company {
+        name: "Apple".into(),
+        ceo: "Tim Cook".into(),
+    };
+
+    let rebranded = Entity::Company {
+        name: "Apple Inc.".into(),
+        ceo: "Tim Cook".into(),
+    };
+
+    let person = Entity::Person {
+        name: "Apple".into(),
+    };
+
+    assert!(apple.same_name(&person));
+    assert!(!apple.same_name(&rebranded));
+
+    assert!(apple.same_ceo(&rebranded));
+    assert!(!apple.same_ceo(&person));
+    assert!(!person.same_ceo(&person));
+
+    let ok = Button::Text {
+        label: "OK".into(),
+        on_click: Box::new(|| 0),
+    };
+
+    let cancel = Button::Text {
+        label: "Cancel".into(),
+        on_click: Box::new(|| 1),
+    };
+
+    assert!(ok.same_label(&ok));
+    assert!(!ok.same_label(&cancel));
+    assert_eq!((ok.on_click())() + (cancel.on_click())(), 1);
+}
@@ -0,0 +1,48 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Color`] annotated with
+//! `#[enum_fields(const_getters)]`.
+//!
+//! The getters are `const fn`s, so they can be used to compute constants, such
+//! as [`PRIMARY_RED`] from [`PRIMARY`].
+
+/// A color in one of two color spaces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(const_getters)]
+pub enum Color {
+    Rgb {
+        red: u8,
+        green: u8,
+        blue: u8,
+    },
+
+    Gray {
+        #[enum_fields(by_value)]
+        level: u8,
+    },
+}
+
+/// The primary color of the application.
+const PRIMARY: Color = Color::Rgb { red: 200, green: 40, blue: 90 };
+
+/// The red component of the primary color.
+const PRIMARY_RED: u8 = match PRIMARY.red() {
+    Some(red) => *red,
+    None => 0,
+};
+
+/// The gray level of the background.
+const BACKGROUND_LEVEL: Option<u8> = Color::Gray { level: 30 }.level();
+
+fn main() {
+    assert_eq!(PRIMARY_RED, 200);
+    assert_eq!(BACKGROUND_LEVEL, Some(30));
+    assert_eq!(PRIMARY.level(), None);
+
+    let mut color = PRIMARY;
+    if let Some(blue) = color.blue_mut() {
+        *blue = 255;
+    }
+    assert_eq!(color.blue(), Some(&255));
+}
@@ -0,0 +1,61 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Job`] with fields that have a
+//! default for the variants without them.
+//!
+//! Only `Remote` jobs have `retries`, but since the field is annotated with
+//! `#[enum_fields(default = "0")]`, `Job::retries()` returns a `&u32` instead
+//! of an `Option<&u32>`, which is `&0` for a `Local` job. The mutable getter
+//! still returns an `Option`, since there is no field to mutate otherwise.
+//!
+//! The `user` of a `Remote` job is a `String`, which can't be a constant, so
+//! it is annotated with `#[enum_fields(or_default)]` instead. `Job::user()`
+//! returns a clone of the `String`, or an empty one for a `Local` job.
+
+/// A job that is run either locally or on a remote machine.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Job {
+    Local {
+        command: String,
+    },
+
+    Remote {
+        command: String,
+
+        #[enum_fields(default = "0")]
+        retries: u32,
+
+        #[enum_fields(default = "\"localhost\"")]
+        host: &'static str,
+
+        #[enum_fields(or_default)]
+        user: String,
+    },
+}
+
+fn main() {
+    let local = Job::Local { command: "make".into() };
+    let mut remote = Job::Remote {
+        command: "make".into(),
+        retries: 3,
+        host: "example.com",
+        user: "admin".into(),
+    };
+
+    let retries: &u32 = local.retries();
+    assert_eq!(*retries, 0);
+    assert_eq!(*remote.retries(), 3);
+
+    assert_eq!(local.host(), "localhost");
+    assert_eq!(remote.host(), "example.com");
+
+    let user: String = local.user();
+    assert_eq!(user, "");
+    assert_eq!(remote.user(), "admin");
+
+    if let Some(retries) = remote.retries_mut() {
+        *retries += 1;
+    }
+    assert_eq!(*remote.retries(), 4);
+}
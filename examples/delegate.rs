@@ -0,0 +1,55 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Event`], of which every variant
+//! contains an [`Origin`], which derives [`enum_fields::EnumFields`] as well.
+//!
+//! Since the `origin` field is annotated with `#[enum_fields(delegate(...))]`,
+//! the listed getters of [`Origin`] are available on [`Event`] directly, e.g.
+//! `Event::address()` calls `Origin::address()` on the origin of the event.
+
+/// Where an event originated from.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Origin {
+    Local {
+        address: String,
+    },
+
+    Remote {
+        address: String,
+        port: u16,
+    },
+}
+
+/// An event received by a server.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Event {
+    Connected {
+        #[enum_fields(delegate(address: &String, port: Option<&u16>))]
+        origin: Origin,
+    },
+
+    Message {
+        #[enum_fields(delegate(address: &String, port: Option<&u16>))]
+        origin: Origin,
+        content: String,
+    },
+}
+
+fn main() {
+    let connected = Event::Connected {
+        origin: Origin::Local { address: "localhost".into() },
+    };
+
+    let message = Event::Message {
+        origin: Origin::Remote { address: "example.com".into(), port: 443 },
+        content: "Hello".into(),
+    };
+
+    assert_eq!(connected.address(), "localhost");
+    assert_eq!(connected.port(), None);
+
+    assert_eq!(message.address(), "example.com");
+    assert_eq!(message.port(), Some(&443));
+    assert_eq!(message.origin().address(), message.address());
+}
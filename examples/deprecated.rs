@@ -0,0 +1,52 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Setting`] with a deprecated
+//! field.
+//!
+//! The `key` field is marked `#[deprecated]`, so `Setting::key()` and
+//! `Setting::key_mut()` are deprecated too, and using them results in a
+//! warning, which is allowed explicitly below.
+
+/// A setting of an application, of which the key was replaced by a path.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(setters)]
+pub enum Setting {
+    Flag {
+        #[deprecated = "use `path` instead"]
+        key: String,
+        path: Vec<String>,
+        enabled: bool,
+    },
+
+    Text {
+        #[deprecated = "use `path` instead"]
+        key: String,
+        path: Vec<String>,
+        content: String,
+    },
+}
+
+#[deny(deprecated)]
+fn path(setting: &Setting) -> String {
+    setting.path().join(".")
+}
+
+#[allow(deprecated)]
+fn main() {
+    let mut setting = Setting::Flag {
+        key: "editor.wrap".into(),
+        path: vec!["editor".into(), "wrap".into()],
+        enabled: true,
+    };
+
+    assert_eq!(path(&setting), "editor.wrap");
+    assert_eq!(setting.key(), "editor.wrap");
+
+    setting.set_key("editor.word_wrap".into());
+    assert_eq!(setting.key(), "editor.word_wrap");
+
+    setting.key_mut().push_str("ping");
+    assert_eq!(setting.key(), "editor.word_wrapping");
+    assert_eq!(setting.enabled(), Some(&true));
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases a recursive enum [`Ast`], which stores its
+//! operands in a `Box`.
+//!
+//! Since the operands are annotated with `#[enum_fields(deref)]`,
+//! `Ast::left()` returns an `Option<&Ast>` instead of an `Option<&Box<Ast>>`,
+//! and `Ast::left_mut()` an `Option<&mut Ast>`.
+//!
+//! The `source` field is an `Rc<str>`, which can't be mutably dereferenced, so
+//! only `Ast::source()` returns the `&str`, while `Ast::source_mut()` returns
+//! the `&mut Rc<str>`. The same goes for the `name` field, which is a
+//! `Cow<'static, str>`.
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+/// A node of an abstract syntax tree.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Ast {
+    Binary {
+        #[enum_fields(deref)]
+        left: Box<Ast>,
+
+        #[enum_fields(deref)]
+        right: Box<Ast>,
+
+        operator: char,
+
+        #[enum_fields(deref)]
+        source: Rc<str>,
+    },
+
+    Literal {
+        value: i64,
+
+        #[enum_fields(deref)]
+        source: Rc<str>,
+    },
+
+    Variable {
+        #[enum_fields(deref)]
+        name: Cow<'static, str>,
+
+        #[enum_fields(deref)]
+        source: Rc<str>,
+    },
+}
+
+fn evaluate(ast: &Ast) -> i64 {
+    match ast.operator() {
+        Some('+') => evaluate(ast.left().unwrap()) + evaluate(ast.right().unwrap()),
+        Some('*') => evaluate(ast.left().unwrap()) * evaluate(ast.right().unwrap()),
+        _ if ast.is_variable() => 0,
+        _ => *ast.value().unwrap(),
+    }
+}
+
+fn main() {
+    let mut ast = Ast::Binary {
+        left: Box::new(Ast::Literal { value: 2, source: "2".into() }),
+        right: Box::new(Ast::Literal { value: 3, source: "3".into() }),
+        operator: '*',
+        source: "2 * 3".into(),
+    };
+
+    assert_eq!(evaluate(&ast), 6);
+    assert_eq!(ast.source(), "2 * 3");
+
+    let left: &Ast = ast.left().unwrap();
+    assert_eq!(left.value(), Some(&2));
+    assert_eq!(left.left(), None);
+
+    if let Some(Ast::Literal { value, .. }) = ast.left_mut() {
+        *value = 4;
+    }
+    assert_eq!(evaluate(&ast), 12);
+
+    *ast.source_mut() = "4 * 3".into();
+    assert_eq!(ast.source(), "4 * 3");
+
+    let mut variable = Ast::Variable { name: Cow::Borrowed("x"), source: "x".into() };
+    let name: Option<&str> = variable.name();
+    assert_eq!(name, Some("x"));
+
+    if let Some(name) = variable.name_mut() {
+        name.to_mut().push('1');
+    }
+    assert_eq!(variable.name(), Some("x1"));
+    assert_eq!(evaluate(&variable), 0);
+}
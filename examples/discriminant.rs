@@ -0,0 +1,72 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases enums of which the variants have explicit
+//! discriminants, like the opcodes of an instruction set.
+//!
+//! The discriminants don't affect the patterns matching the variants, so the
+//! getters work the same as without them. Since [`Instruction`] has variants
+//! with fields, it needs a primitive representation to have discriminants.
+
+/// An instruction of a small virtual machine, with its opcode as discriminant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[repr(u8)]
+pub enum Instruction {
+    Push {
+        value: u8,
+    } = 0x10,
+
+    Load {
+        register: u8,
+        value: u8,
+    } = 0x20,
+
+    Move(u8, u8) = 0x30,
+
+    Halt = 0xFF,
+}
+
+impl Instruction {
+    /// Get the opcode of this instruction, which is the discriminant.
+    pub fn opcode(&self) -> u8 {
+        // SAFETY: The enum is `repr(u8)`, so it starts with its discriminant.
+        unsafe { *(self as *const Self as *const u8) }
+    }
+}
+
+/// A fieldless enum with discriminants, which are computed by expressions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Flag {
+    Read = 1 << 0,
+    Write = 1 << 1,
+    Execute = 1 << 2,
+}
+
+fn main() {
+    let push = Instruction::Push { value: 42 };
+    let mut load = Instruction::Load { register: 1, value: 7 };
+    let mov = Instruction::Move(1, 2);
+
+    assert_eq!(push.value(), Some(&42));
+    assert_eq!(load.value(), Some(&7));
+    assert_eq!(load.register(), Some(&1));
+    assert_eq!(mov.value(), None);
+    assert_eq!(mov.field_0(), Some(&1));
+    assert_eq!(Instruction::Halt.register(), None);
+
+    if let Some(value) = load.value_mut() {
+        *value += 1;
+    }
+    assert_eq!(load, Instruction::Load { register: 1, value: 8 });
+
+    assert!(push.is_push());
+    assert!(Instruction::Halt.is_halt());
+    assert_eq!(mov.variant_name(), "Move");
+
+    assert_eq!(push.opcode(), 0x10);
+    assert_eq!(load.opcode(), 0x20);
+    assert_eq!(Instruction::Halt.opcode(), 0xFF);
+
+    assert!(Flag::Write.is_write());
+    assert_eq!(Flag::Execute as u8, 4);
+}
@@ -0,0 +1,38 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases hiding the generated methods from the
+//! documentation.
+//!
+//! The enum [`Token`] is annotated with `#[enum_fields(doc_hidden)]`, so
+//! `Token::span()`, `Token::is_word()` and the other generated methods are
+//! marked `#[doc(hidden)]`. They are still `pub`, so they can be used from
+//! other modules, like [`lexer`].
+
+mod lexer {
+    /// A token produced by the lexer, which is only used internally.
+    #[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+    #[enum_fields(doc_hidden)]
+    pub enum Token {
+        Word {
+            span: (usize, usize),
+            text: String,
+        },
+
+        Whitespace {
+            span: (usize, usize),
+        },
+    }
+}
+
+use lexer::Token;
+
+fn main() {
+    let word = Token::Word { span: (0, 5), text: "hello".into() };
+    let whitespace = Token::Whitespace { span: (5, 6) };
+
+    assert!(word.is_word());
+    assert_eq!(word.text(), Some(&"hello".into()));
+    assert_eq!(whitespace.span(), &(5, 6));
+    assert_eq!(Token::VARIANTS, ["Word", "Whitespace"]);
+}
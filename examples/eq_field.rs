@@ -0,0 +1,34 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`] annotated with
+//! `#[enum_fields(eq_field = "id")]`.
+//!
+//! This generates `Entity::eq_by_id()`, which considers two entities equal
+//! when their `id` is, regardless of their other fields or variant. The enum
+//! still derives [`PartialEq`] as well, which compares every field.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(eq_field = "id")]
+pub enum Entity {
+    Company {
+        id: u64,
+        name: String,
+    },
+
+    Person {
+        id: u64,
+        name: String,
+    },
+}
+
+fn main() {
+    let apple = Entity::Company { id: 1, name: "Apple".into() };
+    let renamed = Entity::Company { id: 1, name: "Apple Inc.".into() };
+    let person = Entity::Person { id: 2, name: "Tim Berners-Lee".into() };
+
+    assert!(apple.eq_by_id(&renamed));
+    assert_ne!(apple, renamed);
+    assert!(!apple.eq_by_id(&person));
+}
@@ -0,0 +1,51 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Setting`] of which the fields
+//! carry attributes other than `#[enum_fields(...)]`.
+//!
+//! The derive only reads the `#[enum_fields(...)]` attributes, and ignores any
+//! other attribute, such as documentation, lints and tool attributes like
+//! `#[rustfmt::skip]`. Since `#[cfg(...)]` and `#[cfg_attr(...)]` are evaluated
+//! before the derive runs, fields that are configured out don't get getters,
+//! and `#[enum_fields(...)]` attributes inside `#[cfg_attr(...)]` apply when
+//! the condition holds.
+
+/// A setting that is either a toggle or a value within a range.
+#[derive(Clone, Debug, PartialEq, enum_fields::EnumFields)]
+pub enum Setting {
+    Toggle {
+        /// The name of the setting, shown to the user.
+        #[rustfmt::skip]
+        name:   String,
+
+        #[allow(clippy::struct_field_names)]
+        enabled: bool,
+    },
+
+    Range {
+        /// The name of the setting, shown to the user.
+        #[rustfmt::skip]
+        name:   String,
+
+        #[cfg_attr(all(), enum_fields(rename = "current"))]
+        value: i64,
+
+        #[cfg_attr(any(), enum_fields(skip))]
+        maximum: i64,
+
+        #[cfg(any())]
+        minimum: i64,
+    },
+}
+
+fn main() {
+    let toggle = Setting::Toggle { name: "Dark mode".into(), enabled: true };
+    let range = Setting::Range { name: "Volume".into(), value: 7, maximum: 10 };
+
+    assert_eq!(toggle.name(), "Dark mode");
+    assert_eq!(toggle.enabled(), Some(&true));
+    assert_eq!(range.current(), Some(&7));
+    assert_eq!(range.maximum(), Some(&10));
+    assert_eq!(range.field_names(), ["name", "value", "maximum"]);
+}
@@ -0,0 +1,30 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases a C-like enum [`Color`], which doesn't
+//! have any fields.
+//!
+//! Even though there aren't any fields to generate getters for, the variant
+//! helpers are still generated: `Color::is_red()`, `Color::variant_name()`
+//! and so on.
+
+/// A primary color, represented as a byte.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[repr(u8)]
+pub enum Color {
+    Red = 1,
+    Green = 2,
+    Blue = 4,
+}
+
+fn main() {
+    let color = Color::Green;
+
+    assert!(color.is_green());
+    assert!(!color.is_red());
+    assert!(Color::Blue.is_blue());
+
+    assert_eq!(color.variant_name(), "Green");
+    assert_eq!(color.field_names(), &[] as &[&str]);
+    assert_eq!(color as u8, 2);
+}
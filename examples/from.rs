@@ -0,0 +1,46 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases the `name` field of an enum [`Entity`]
+//! annotated with `#[enum_fields(from)]`.
+//!
+//! A `Person` holds nothing but a `name`, so `Entity::into_name_opt()` returns
+//! it, whereas a `Company` would lose its `ceo`, so `None` is returned. The
+//! `Id` variant wraps a single positional field, so `Entity::into_field_0_opt()`
+//! extracts it.
+
+/// An entity that can be either a `Company`, a `Person` or an `Id`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        #[enum_fields(from)]
+        name: String,
+    },
+
+    Id(#[enum_fields(from)] u32),
+}
+
+fn main() {
+    let company = Entity::Company {
+        name: "Apple".into(),
+        ceo: "Tim Cook".into(),
+    };
+
+    let person = Entity::Person {
+        name: "Tim Berners-Lee".into(),
+    };
+
+    let id = Entity::Id(42);
+
+    assert_eq!(person.clone().into_name_opt(), Some("Tim Berners-Lee".into()));
+    assert_eq!(company.clone().into_name_opt(), None);
+    assert_eq!(id.clone().into_name_opt(), None);
+
+    assert_eq!(id.into_field_0_opt(), Some(42));
+    assert_eq!(person.into_field_0_opt(), None);
+}
@@ -0,0 +1,213 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases that [`enum_fields::EnumFields`] can be
+//! derived for generic enums, including ones with lifetimes, trait bounds,
+//! `where` clauses and const generics. Type parameters that are only used as
+//! a marker, or that aren't `Sized`, are supported as well.
+//!
+//! The types of the fields are reproduced as written in the return types of
+//! the getters, even when deeply nested, like the fields of [`Index`].
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+/// A borrowed token, using a lifetime parameter.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Token<'a> {
+    Word {
+        text: &'a str,
+    },
+
+    Number {
+        text: &'a str,
+        value: i64,
+    },
+}
+
+/// A tree with a trait bound on its type parameter.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Tree<T: Clone> {
+    Leaf {
+        value: T,
+    },
+
+    Node {
+        value: T,
+        children: Vec<Tree<T>>,
+    },
+}
+
+/// A labeled value, using a `where` clause.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Labeled<L, V>
+        where L: Display {
+    Single {
+        label: L,
+        value: V,
+    },
+
+    Unlabeled {
+        value: V,
+    },
+}
+
+/// A fixed-size buffer, using a const generic parameter.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Buf<const N: usize> {
+    Full {
+        data: [u8; N],
+    },
+
+    Empty,
+}
+
+/// A window into a buffer, combining a lifetime with a const generic.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Window<'a, const N: usize> {
+    Borrowed {
+        data: &'a [u8; N],
+        offset: usize,
+    },
+
+    Owned {
+        data: &'a [u8; N],
+    },
+}
+
+/// A container of which the field type is the type parameter itself.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Container<T> {
+    One {
+        item: T,
+    },
+
+    Many {
+        item: T,
+        count: usize,
+    },
+}
+
+/// A key of a map, of which only the key type is used in a field.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Keyed<K, V> {
+    Present {
+        key: K,
+        marker: PhantomData<V>,
+    },
+
+    Missing {
+        key: K,
+    },
+}
+
+/// An identifier of a value, of which the type parameter is only used as a
+/// marker and doesn't need to be `Sized`.
+#[derive(Debug, enum_fields::EnumFields)]
+pub enum Id<U: ?Sized> {
+    Numeric {
+        id: u64,
+        marker: PhantomData<U>,
+    },
+}
+
+/// An index over the items of an iterator, with deeply nested field types that
+/// are shared between the variants, even when written with different spacing.
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(map)]
+pub enum Index<K, V, I: Iterator> {
+    Loaded {
+        tables: Vec<HashMap<K, Vec<Option<V>>>>,
+        #[enum_fields(clone)]
+        next: Option<<I as Iterator>::Item>,
+        lookup: Box<dyn Fn(&K) -> Option<[V; 2]>>,
+    },
+
+    Cached {
+        tables: Vec<HashMap<K,Vec<Option<V>>>>,
+        next: Option< <I as Iterator>::Item >,
+        ranges: std::collections::BTreeMap<K, Vec<std::ops::Range<usize>>>,
+    },
+}
+
+fn main() {
+    let word = Token::Word { text: "hello" };
+    let number = Token::Number { text: "42", value: 42 };
+    assert_eq!(word.text(), "hello");
+    assert_eq!(number.value(), Some(&42));
+    assert_eq!(word.value(), None);
+
+    let mut tree = Tree::Node {
+        value: 1,
+        children: vec![Tree::Leaf { value: 2 }],
+    };
+    assert_eq!(*tree.value(), 1);
+    assert_eq!(tree.children().map(Vec::len), Some(1));
+
+    *tree.value_mut() = 3;
+    assert_eq!(*tree.value(), 3);
+
+    let single = Labeled::Single { label: "answer", value: 42 };
+    let unlabeled: Labeled<&str, i32> = Labeled::Unlabeled { value: 7 };
+    assert_eq!(single.label(), Some(&"answer"));
+    assert_eq!(unlabeled.label(), None);
+    assert_eq!(*unlabeled.value(), 7);
+
+    let mut full = Buf::Full { data: [1, 2, 3, 4] };
+    let empty = Buf::<4>::Empty;
+    assert_eq!(full.data(), Some(&[1, 2, 3, 4]));
+    assert_eq!(empty.data(), None);
+
+    if let Some(data) = full.data_mut() {
+        data[0] = 5;
+    }
+    assert_eq!(full.data(), Some(&[5, 2, 3, 4]));
+
+    let bytes = [7; 8];
+    let mut window = Window::Borrowed { data: &bytes, offset: 2 };
+    assert_eq!(window.data()[2], 7);
+    assert_eq!(window.offset(), Some(&2));
+
+    let other = [9; 8];
+    *window.data_mut() = &other;
+    assert_eq!(*window.data(), [9; 8]);
+
+    let one = Container::One { item: String::from("apple") };
+    let many = Container::Many { item: 'a', count: 3 };
+    let item: &String = one.item();
+    assert_eq!(item, "apple");
+    assert_eq!(*many.item(), 'a');
+    assert_eq!(many.count(), Some(&3));
+
+    let present: Keyed<&str, Vec<u8>> = Keyed::Present { key: "data", marker: PhantomData };
+    let missing: Keyed<u32, String> = Keyed::Missing { key: 4 };
+    assert_eq!(*present.key(), "data");
+    assert_eq!(*missing.key(), 4);
+    assert_eq!(missing.marker(), None);
+
+    let id: Id<str> = Id::Numeric { id: 12, marker: PhantomData };
+    assert_eq!(*id.id(), 12);
+    assert!(id.is_numeric());
+
+    type Chars = std::vec::IntoIter<char>;
+    let loaded: Index<&str, u8, Chars> = Index::Loaded {
+        tables: vec![HashMap::from([("a", vec![Some(1), None])])],
+        next: Some('x'),
+        lookup: Box::new(|key| (*key == "a").then_some([1, 2])),
+    };
+    let cached: Index<&str, u8, Chars> = Index::Cached {
+        tables: Vec::new(),
+        next: None,
+        ranges: BTreeMap::from([("a", vec![0..2, 4..6])]),
+    };
+
+    let tables: &Vec<HashMap<&str, Vec<Option<u8>>>> = loaded.tables();
+    assert_eq!(tables[0]["a"], [Some(1), None]);
+    assert_eq!(loaded.next(), &Some('x'));
+    assert_eq!(cached.next_cloned(), None);
+    assert_eq!(loaded.map_lookup(|lookup| lookup(&"a")), Some(Some([1, 2])));
+    assert_eq!(cached.ranges().map(|ranges| ranges[&"a"].clone()), Some(vec![0..2, 4..6]));
+}
@@ -0,0 +1,33 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`] of which the `name`
+//! field is annotated with `#[enum_fields(getter_doc = "...")]`.
+//!
+//! The documentation of `Entity::name()` and `Entity::name_mut()` is the one
+//! given in the attribute, instead of the generic documentation the other
+//! getters like `Entity::ceo()` have.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Entity {
+    Company {
+        #[enum_fields(getter_doc = "The registered name of the company or person.")]
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+fn main() {
+    let mut person = Entity::Person {
+        name: "Tim Berners-Lee".into(),
+    };
+
+    person.name_mut().push_str(" OM");
+    assert_eq!(person.name(), "Tim Berners-Lee OM");
+    assert_eq!(person.ceo(), None);
+}
@@ -0,0 +1,55 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases the `get_` naming convention for getters.
+//!
+//! The enum [`Entity`] is annotated with `#[enum_fields(getter_prefix = "get_")]`,
+//! so its getters are named `Entity::get_name()` and `Entity::get_name_mut()`,
+//! while the getters of [`Animal`] keep the bare field names.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(getter_prefix = "get_")]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+/// An animal, using the default names for its getters.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Animal {
+    Cat {
+        name: String,
+    },
+
+    Dog {
+        name: String,
+    },
+}
+
+fn main() {
+    let mut company = Entity::Company {
+        name: "Apple".into(),
+        ceo: "Tim Cook".into(),
+    };
+
+    let person = Entity::Person {
+        name: "Tim Berners-Lee".into(),
+    };
+
+    assert_eq!(company.get_name(), "Apple");
+    assert_eq!(company.get_ceo(), Some(&"Tim Cook".into()));
+    assert_eq!(person.get_ceo(), None);
+
+    *company.get_name_mut() = "Microsoft".into();
+    assert_eq!(company.get_name(), "Microsoft");
+
+    let cat = Animal::Cat { name: "Tom".into() };
+    assert_eq!(cat.name(), "Tom");
+}
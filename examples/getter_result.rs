@@ -0,0 +1,73 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`] annotated with
+//! `#[enum_fields(try, getter_result = "LookupError")]`.
+//!
+//! The `Entity::try_ceo()` getter returns a `Result<&String, LookupError>`
+//! instead of using the [`EnumFieldError`], so it can be used with the `?`
+//! operator in functions returning a [`LookupError`].
+
+use enum_fields::EnumFieldError;
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(try, getter_result = "LookupError")]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+/// The error of looking up information about an [`Entity`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum LookupError {
+    NotFound,
+    MissingField {
+        message: String,
+    },
+}
+
+impl From<EnumFieldError> for LookupError {
+    fn from(error: EnumFieldError) -> Self {
+        Self::MissingField {
+            message: error.to_string(),
+        }
+    }
+}
+
+/// Looks up the CEO of an entity by its name.
+fn find_ceo<'a>(entities: &'a [Entity], name: &str) -> Result<&'a String, LookupError> {
+    let entity = entities.iter()
+        .find(|entity| entity.name() == name)
+        .ok_or(LookupError::NotFound)?;
+
+    let ceo = entity.try_ceo()?;
+    Ok(ceo)
+}
+
+fn main() {
+    let entities = [
+        Entity::Company {
+            name: "Apple".into(),
+            ceo: "Tim Cook".into(),
+        },
+        Entity::Person {
+            name: "Tim Berners-Lee".into(),
+        },
+    ];
+
+    assert_eq!(find_ceo(&entities, "Apple"), Ok(&"Tim Cook".into()));
+    assert_eq!(find_ceo(&entities, "Microsoft"), Err(LookupError::NotFound));
+    assert_eq!(find_ceo(&entities, "Tim Berners-Lee"), Err(LookupError::MissingField {
+        message: "variant `Person` doesn't have the field `ceo`".into(),
+    }));
+
+    let error: LookupError = entities[1].try_ceo().unwrap_err();
+    assert!(matches!(error, LookupError::MissingField { .. }));
+}
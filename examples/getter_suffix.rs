@@ -0,0 +1,56 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`] annotated with
+//! `#[enum_fields(getter_suffix = "_ref")]`.
+//!
+//! The getters are named `Entity::name_ref()` and `Entity::ceo_ref()`, which
+//! leaves `Entity::name()` free for a hand-written method returning an owned
+//! value. The mutable getters are still named `Entity::name_mut()` and
+//! `Entity::ceo_mut()`.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(getter_suffix = "_ref")]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+impl Entity {
+    /// Get the name, formatted for display.
+    pub fn name(&self) -> String {
+        match self.ceo_ref() {
+            Some(ceo) => format!("{} (led by {ceo})", self.name_ref()),
+            None => self.name_ref().clone(),
+        }
+    }
+}
+
+fn main() {
+    let mut company = Entity::Company {
+        name: "Apple".into(),
+        ceo: "Tim Cook".into(),
+    };
+
+    let person = Entity::Person {
+        name: "Tim Berners-Lee".into(),
+    };
+
+    assert_eq!(company.name_ref(), "Apple");
+    assert_eq!(company.name(), "Apple (led by Tim Cook)");
+    assert_eq!(person.ceo_ref(), None);
+    assert_eq!(person.name(), "Tim Berners-Lee");
+
+    *company.name_mut() = "Microsoft".into();
+    if let Some(ceo) = company.ceo_mut() {
+        *ceo = "Satya Nadella".into();
+    }
+    assert_eq!(company.name(), "Microsoft (led by Satya Nadella)");
+}
@@ -0,0 +1,45 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Message`] with a sentinel
+//! variant `Unknown`, for messages that couldn't be parsed.
+//!
+//! Since `Unknown` is annotated with `#[enum_fields(ignore)]`, it doesn't take
+//! part in sharing fields. Every other variant has an `id`, so
+//! `Message::id()` returns a `&u32` instead of an `Option<&u32>`. For
+//! `Unknown`, it returns the default of the field, which is therefore
+//! required. The `text` field isn't present in every other variant, so
+//! `Message::text()` still returns an `Option`, which is `None` for `Unknown`.
+
+/// A message received from a client.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(try)]
+pub enum Message {
+    Chat {
+        #[enum_fields(default = "0")]
+        id: u32,
+        text: String,
+    },
+
+    Leave {
+        id: u32,
+    },
+
+    #[enum_fields(ignore)]
+    Unknown(String),
+}
+
+fn main() {
+    let chat = Message::Chat { id: 1, text: "Hello".into() };
+    let leave = Message::Leave { id: 2 };
+    let unknown = Message::Unknown("?".into());
+
+    let id: &u32 = chat.id();
+    assert_eq!(*id, 1);
+    assert_eq!(*leave.id(), 2);
+    assert_eq!(*unknown.id(), 0);
+
+    assert_eq!(chat.text(), Some(&"Hello".into()));
+    assert_eq!(unknown.text(), None);
+    assert_eq!(unknown.try_text().unwrap_err().variant, "Unknown");
+}
@@ -0,0 +1,62 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases a recursive enum [`Expr`] annotated with
+//! `#[enum_fields(trait_name = "ExprFields", impl_for_box)]`.
+//!
+//! The operands of an [`Expr`] are boxed, and since the [`ExprFields`] trait is
+//! implemented for `Box<Expr>` as well, they can be passed to functions
+//! generic over the trait without unboxing them first.
+
+/// An arithmetic expression, of which every node has a source position.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(trait_name = "ExprFields", impl_for_box)]
+pub enum Expr {
+    Number {
+        position: usize,
+        value: i64,
+    },
+
+    Add {
+        position: usize,
+        lhs: Box<Expr>,
+        rhs: Box<Expr>,
+    },
+}
+
+fn position(expr: &impl ExprFields) -> usize {
+    *expr.position()
+}
+
+fn describe<E: ExprFields>(expr: &E) -> String {
+    match (expr.value(), expr.lhs(), expr.rhs()) {
+        (Some(value), _, _) => value.to_string(),
+        (_, Some(lhs), Some(rhs)) => format!("({} + {})", describe(lhs), describe(rhs)),
+        _ => unreachable!(),
+    }
+}
+
+fn main() {
+    let expr = Expr::Add {
+        position: 0,
+        lhs: Box::new(Expr::Number {
+            position: 1,
+            value: 2,
+        }),
+        rhs: Box::new(Expr::Number {
+            position: 5,
+            value: 3,
+        }),
+    };
+
+    // The getter of `lhs` returns a `&Box<Expr>`, which is passed as is.
+    assert_eq!(position(expr.lhs().unwrap()), 1);
+    assert_eq!(position(expr.rhs().unwrap()), 5);
+    assert_eq!(position(&expr), 0);
+
+    // The trait methods are implemented for the box itself.
+    let boxed: Box<Expr> = Box::new(expr.clone());
+    assert_eq!(ExprFields::position(&boxed), &0);
+    assert_eq!(describe(&boxed), "(2 + 3)");
+    assert_eq!(describe(&expr), "(2 + 3)");
+}
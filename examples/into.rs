@@ -0,0 +1,70 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`], which is annotated with
+//! `#[enum_fields(into)]`.
+//!
+//! Next to the regular getters, this generates consuming getters that move the
+//! field out of the enum: `Entity::into_name()` returns a `String`, and
+//! `Entity::into_ceo()` returns an `Option<String>`, since only `Company` has
+//! a `ceo` field.
+//!
+//! The fields of an enum are always `Sized`, so they can always be moved out.
+//! The generic enum [`Handle`] allows an unsized `T`, which is only stored
+//! behind a `Box`, so `Handle::into_target()` returns a `Box<T>` both for
+//! `Handle<str>` and for `Handle<u32>`.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(into)]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+/// A handle to a value, which can be unsized.
+#[derive(Debug, enum_fields::EnumFields)]
+#[enum_fields(into)]
+pub enum Handle<T: ?Sized> {
+    Anonymous {
+        target: Box<T>,
+    },
+
+    Named {
+        target: Box<T>,
+        name: String,
+    },
+}
+
+fn main() {
+    let company = Entity::Company {
+        name: "Apple".into(),
+        ceo: "Tim Cook".into(),
+    };
+
+    let person = Entity::Person {
+        name: "Tim Berners-Lee".into(),
+    };
+
+    // The enum is consumed, and the value is owned by the caller.
+    let mut name: String = company.clone().into_name();
+    name.push_str(" Inc.");
+    assert_eq!(name, "Apple Inc.");
+
+    assert_eq!(company.into_ceo(), Some("Tim Cook".into()));
+    assert_eq!(person.clone().into_ceo(), None);
+    assert_eq!(person.into_name(), "Tim Berners-Lee");
+
+    let text: Handle<str> = Handle::Named { target: "text".into(), name: "greeting".into() };
+    let target: Box<str> = text.into_target();
+    assert_eq!(&*target, "text");
+
+    let number = Handle::Anonymous { target: Box::new(42_u32) };
+    assert_eq!(number.into_name(), None);
+}
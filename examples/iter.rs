@@ -0,0 +1,46 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Post`], which is annotated with
+//! `#[enum_fields(iter)]`.
+//!
+//! For every `Vec<T>` field, this generates an additional getter returning an
+//! iterator over its elements: `Post::tags_iter()` returns a
+//! `std::slice::Iter<'_, String>`, and `Post::reviewers_iter()` an
+//! `Option<std::slice::Iter<'_, String>>`.
+
+/// A post on a blog, which is either published or still a draft.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(iter)]
+pub enum Post {
+    Article {
+        title: String,
+        tags: Vec<String>,
+    },
+
+    Draft {
+        title: String,
+        tags: Vec<String>,
+        reviewers: Vec<String>,
+    },
+}
+
+fn main() {
+    let article = Post::Article {
+        title: "Enums in Rust".into(),
+        tags: vec!["rust".into(), "enums".into()],
+    };
+
+    let draft = Post::Draft {
+        title: "Traits in Rust".into(),
+        tags: Vec::new(),
+        reviewers: vec!["Alice".into()],
+    };
+
+    let tags: Vec<&str> = article.tags_iter().map(String::as_str).collect();
+    assert_eq!(tags, ["rust", "enums"]);
+    assert_eq!(draft.tags_iter().len(), 0);
+
+    assert!(article.reviewers_iter().is_none());
+    assert_eq!(draft.reviewers_iter().map(Iterator::count), Some(1));
+}
@@ -0,0 +1,48 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Token`], of which the fields are
+//! named after the keywords `type` and `match`.
+//!
+//! These fields can only be declared using raw identifiers, so their getters
+//! are raw identifiers as well: `Token::r#type()` and `Token::r#match()`. The
+//! mutable getters aren't keywords and therefore have their regular names:
+//! `Token::type_mut()` and `Token::match_mut()`.
+
+/// A token produced by a lexer.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Token {
+    Keyword {
+        r#type: String,
+        r#match: bool,
+    },
+
+    Identifier {
+        r#type: String,
+    },
+}
+
+fn main() {
+    let mut keyword = Token::Keyword {
+        r#type: "keyword".into(),
+        r#match: true,
+    };
+
+    let identifier = Token::Identifier {
+        r#type: "identifier".into(),
+    };
+
+    assert_eq!(keyword.r#type(), "keyword");
+    assert_eq!(identifier.r#type(), "identifier");
+
+    assert_eq!(keyword.r#match(), Some(&true));
+    assert_eq!(identifier.r#match(), None);
+
+    keyword.type_mut().push_str("-like");
+    assert_eq!(keyword.r#type(), "keyword-like");
+
+    if let Some(r#match) = keyword.match_mut() {
+        *r#match = false;
+    }
+    assert_eq!(keyword.r#match(), Some(&false));
+}
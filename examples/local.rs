@@ -0,0 +1,64 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases deriving [`enum_fields::EnumFields`] for
+//! enums that are declared inside a function body, instead of in a module.
+//!
+//! The enum `Shape` is declared inside `main()`, and the enum `Token` inside
+//! the method `Lexer::first_word_len()`. Their methods, such as
+//! `Shape::radius()` and `Token::map_text()`, work the same as for any other
+//! enum.
+
+/// A lexer producing a single kind of token.
+struct Lexer {
+    input: &'static str,
+}
+
+impl Lexer {
+    /// Returns the length of the first word of the input, if any.
+    fn first_word_len(&self) -> Option<usize> {
+        #[derive(enum_fields::EnumFields)]
+        #[enum_fields(setters, map)]
+        enum Token {
+            Word {
+                text: &'static str,
+            },
+
+            End,
+        }
+
+        let token = match self.input.split_whitespace().next() {
+            Some(text) => Token::Word { text },
+            None => Token::End,
+        };
+
+        assert_eq!(Token::VARIANTS, ["Word", "End"]);
+        token.map_text(|text| text.len())
+    }
+}
+
+fn main() {
+    #[derive(Clone, Copy, Debug, PartialEq, enum_fields::EnumFields)]
+    #[enum_fields(setters, with, into, try, unwrap, compare)]
+    enum Shape {
+        Circle {
+            radius: f64,
+        },
+
+        Square(f64),
+    }
+
+    let mut circle = Shape::Circle { radius: 1.5 };
+    assert!(circle.is_circle());
+    assert_eq!(circle.radius(), Some(&1.5));
+    assert_eq!(Shape::Square(2.0).field_0(), Some(&2.0));
+
+    assert!(circle.set_radius(2.0));
+    assert_eq!(circle.unwrap_radius(), &2.0);
+    assert!(circle.same_radius(&Shape::Circle { radius: 2.0 }));
+    assert!(Shape::Square(1.0).try_radius().is_err());
+    assert_eq!(circle.with_radius(3.0).into_radius(), Some(3.0));
+
+    let lexer = Lexer { input: "hello world" };
+    assert_eq!(lexer.first_word_len(), Some(5));
+}
@@ -0,0 +1,51 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`] annotated with
+//! `#[enum_fields(map)]`.
+//!
+//! For every field, a method is generated that applies a function to the
+//! field, e.g. `Entity::map_name()` returning the result directly, and
+//! `Entity::map_ceo()` returning an `Option` of it, since a `Person` doesn't
+//! have a `ceo`.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(map)]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+fn main() {
+    let company = Entity::Company {
+        name: "Apple".into(),
+        ceo: "Tim Cook".into(),
+    };
+
+    let person = Entity::Person {
+        name: "Tim Berners-Lee".into(),
+    };
+
+    assert_eq!(company.map_name(String::len), 5);
+    assert!(person.map_name(|name| name.starts_with("Tim")));
+
+    let initials = company.map_ceo(|ceo| {
+        ceo.split(' ')
+            .filter_map(|part| part.chars().next())
+            .collect::<String>()
+    });
+    assert_eq!(initials.as_deref(), Some("TC"));
+    assert_eq!(person.map_ceo(|ceo| ceo.to_uppercase()), None);
+
+    let shout = company.map_ceo(|ceo| ceo.to_uppercase())
+        .map(|ceo| format!("{ceo}!"))
+        .unwrap_or_default();
+    assert_eq!(shout, "TIM COOK!");
+}
@@ -0,0 +1,46 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Value`], which contains a
+//! variant of every shape: a struct variant (`Named`), a tuple variant
+//! (`Anonymous`) and a unit variant (`Nothing`).
+//!
+//! Only `Named` has a field called `name`, so `Value::name()` returns an
+//! `Option<&String>`, even though `Anonymous` holds a `String` as well: fields
+//! of tuple variants are only available through their positional getters,
+//! such as `Value::field_0()`.
+
+/// A value that may or may not have a name.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Value {
+    Named {
+        name: String,
+    },
+
+    Anonymous(String),
+
+    Nothing,
+}
+
+fn main() {
+    let mut named = Value::Named { name: "x".into() };
+    let anonymous = Value::Anonymous("y".into());
+    let nothing = Value::Nothing;
+
+    assert_eq!(named.name(), Some(&"x".into()));
+    assert_eq!(anonymous.name(), None);
+    assert_eq!(nothing.name(), None);
+
+    assert_eq!(named.field_0(), None);
+    assert_eq!(anonymous.field_0(), Some(&"y".into()));
+    assert_eq!(nothing.field_0(), None);
+
+    if let Some(name) = named.name_mut() {
+        name.push('!');
+    }
+    assert_eq!(named, Value::Named { name: "x!".into() });
+
+    assert!(named.is_named());
+    assert!(anonymous.is_anonymous());
+    assert!(nothing.is_nothing());
+}
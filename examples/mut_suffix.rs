@@ -0,0 +1,52 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`] which already has a
+//! hand-written `Entity::name_mut()` method.
+//!
+//! Since the enum is annotated with `#[enum_fields(mut_suffix = "_mut_ref")]`,
+//! the generated mutable getters are named `Entity::name_mut_ref()` and
+//! `Entity::ceo_mut_ref()` instead, so they don't clash with it.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(mut_suffix = "_mut_ref")]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+impl Entity {
+    /// Get the name, marking it as modified.
+    pub fn name_mut(&mut self) -> &mut String {
+        let name = self.name_mut_ref();
+        if !name.ends_with('*') {
+            name.push('*');
+        }
+        name
+    }
+}
+
+fn main() {
+    let mut company = Entity::Company {
+        name: "Apple".into(),
+        ceo: "Tim Cook".into(),
+    };
+
+    *company.name_mut_ref() = "Microsoft".into();
+    assert_eq!(company.name(), "Microsoft");
+
+    company.name_mut().push_str(" Corporation");
+    assert_eq!(company.name(), "Microsoft* Corporation");
+
+    if let Some(ceo) = company.ceo_mut_ref() {
+        *ceo = "Satya Nadella".into();
+    }
+    assert_eq!(company.ceo(), Some(&"Satya Nadella".into()));
+}
@@ -0,0 +1,41 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Event`], which is marked
+//! `#[non_exhaustive]`.
+//!
+//! Getters of fields that aren't present in every variant, such as
+//! `Event::key()`, get a trailing wildcard arm returning `None`. The `time`
+//! field is present in every variant, so `Event::time()` returns the field
+//! directly.
+
+/// An input event.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(setters)]
+#[non_exhaustive]
+pub enum Event {
+    Keyboard {
+        time: u64,
+        key: char,
+    },
+
+    Click {
+        time: u64,
+        button: u8,
+    },
+}
+
+fn main() {
+    let mut key = Event::Keyboard { time: 1, key: 'a' };
+    let click = Event::Click { time: 2, button: 0 };
+
+    assert_eq!(*key.time(), 1);
+    assert_eq!(*click.time(), 2);
+
+    assert_eq!(key.key(), Some(&'a'));
+    assert_eq!(click.key(), None);
+    assert_eq!(click.button(), Some(&0));
+
+    assert!(key.set_key('b'));
+    assert_eq!(key.key(), Some(&'b'));
+}
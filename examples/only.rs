@@ -0,0 +1,46 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Event`] annotated with
+//! `#[enum_fields(only(id, timestamp))]`.
+//!
+//! Only the listed fields get methods, so there are getters such as
+//! `Event::id()` and `Event::timestamp()`, but none for the other fields like
+//! `payload` and `key`. These can even have different types in different
+//! variants, since they don't share a getter.
+
+/// An event emitted by an application.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(only(id, timestamp))]
+pub enum Event {
+    Click {
+        id: u64,
+        timestamp: u64,
+        payload: (i32, i32),
+    },
+
+    KeyPress {
+        id: u64,
+        timestamp: u64,
+        payload: char,
+        key: u32,
+    },
+
+    Resize {
+        id: u64,
+        payload: (u32, u32),
+    },
+}
+
+fn main() {
+    let click = Event::Click { id: 1, timestamp: 100, payload: (10, 20) };
+    let resize = Event::Resize { id: 2, payload: (800, 600) };
+
+    assert_eq!(click.id(), &1);
+    assert_eq!(click.timestamp(), Some(&100));
+    assert_eq!(resize.timestamp(), None);
+
+    // The variant methods are generated regardless of the fields.
+    assert!(resize.is_resize());
+    assert_eq!(resize.field_names(), ["id", "payload"]);
+}
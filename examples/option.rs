@@ -0,0 +1,97 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`], of which the fields
+//! are `Option`s.
+//!
+//! The `name` field is present in every variant, so `Entity::name()` returns
+//! an `&Option<String>` like any other field. It is annotated with
+//! `#[enum_fields(option_mut_inner)]`, so `Entity::name_mut()` returns an
+//! `Option<&mut String>` instead of an `&mut Option<String>`, to modify the
+//! name in place.
+//!
+//! The `website` field is only present in `Company`, which would make
+//! `Entity::website()` return an `Option<&Option<String>>`. Since it is
+//! annotated with `#[enum_fields(flatten_option)]`, it returns an
+//! `Option<&String>` instead: `None` for a `Person`, and for a `Company`
+//! without a website.
+//!
+//! The enum [`Contact`] is annotated with `#[enum_fields(flatten_option)]`,
+//! which flattens every `Option` field that isn't present in every variant, so
+//! `Contact::phone()` returns an `Option<&u64>` as well.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Entity {
+    Company {
+        #[enum_fields(option_mut_inner)]
+        name: Option<String>,
+
+        #[enum_fields(flatten_option)]
+        website: Option<String>,
+    },
+
+    Person {
+        #[enum_fields(option_mut_inner)]
+        name: Option<String>,
+    },
+}
+
+/// A way to contact an entity.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(flatten_option)]
+pub enum Contact {
+    Call {
+        phone: Option<u64>,
+        note: Option<String>,
+    },
+
+    Letter {
+        note: Option<String>,
+        address: String,
+    },
+}
+
+fn main() {
+    let mut company = Entity::Company {
+        name: Some("Apple".into()),
+        website: Some("apple.com".into()),
+    };
+
+    let mut anonymous = Entity::Company {
+        name: None,
+        website: None,
+    };
+
+    let person = Entity::Person {
+        name: Some("Tim Berners-Lee".into()),
+    };
+
+    assert_eq!(company.name(), &Some("Apple".into()));
+    assert_eq!(anonymous.name(), &None);
+
+    if let Some(name) = company.name_mut() {
+        name.push_str(" Inc.");
+    }
+    assert_eq!(company.name(), &Some("Apple Inc.".into()));
+    assert_eq!(anonymous.name_mut(), None);
+
+    assert_eq!(company.website(), Some(&"apple.com".into()));
+    assert_eq!(anonymous.website(), None);
+    assert_eq!(person.website(), None);
+
+    let call = Contact::Call { phone: Some(5550100), note: None };
+    let unlisted = Contact::Call { phone: None, note: None };
+    let letter = Contact::Letter { note: Some("Urgent".into()), address: "Main Street".into() };
+
+    // A variant with the field, a variant where it is `None`, and a variant
+    // without it.
+    assert_eq!(call.phone(), Some(&5550100));
+    assert_eq!(unlisted.phone(), None);
+    assert_eq!(letter.phone(), None);
+    assert!(unlisted.is_call() && !letter.is_call());
+
+    // `note` is present in every variant, so it isn't flattened.
+    assert_eq!(letter.note(), &Some("Urgent".into()));
+    assert_eq!(letter.address(), Some(&"Main Street".into()));
+}
@@ -0,0 +1,44 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`] annotated with
+//! `#[enum_fields(or)]`.
+//!
+//! Only `Company` has a `ceo`, so `Entity::ceo_or()` is generated, which
+//! returns the `ceo` of a `Company`, and the given default for a `Person`.
+//! Since every variant has a `name`, no `Entity::name_or()` is generated.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(or)]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+fn main() {
+    let company = Entity::Company {
+        name: "Apple".into(),
+        ceo: "Tim Cook".into(),
+    };
+
+    let person = Entity::Person {
+        name: "Tim Berners-Lee".into(),
+    };
+
+    let nobody = String::from("nobody");
+    assert_eq!(company.ceo_or(&nobody), "Tim Cook");
+    assert_eq!(person.ceo_or(&nobody), "nobody");
+
+    // The returned reference can borrow from either the enum or the default.
+    let ceos: Vec<&String> = [&company, &person].into_iter()
+        .map(|entity| entity.ceo_or(&nobody))
+        .collect();
+    assert_eq!(ceos, ["Tim Cook", "nobody"]);
+}
@@ -0,0 +1,43 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Command`], which is annotated
+//! with `#[enum_fields(path_accessors)]`.
+//!
+//! For every `PathBuf` field, this generates an additional getter returning a
+//! path slice: `Command::input_path()` returns a `&Path`, and
+//! `Command::output_path()` an `Option<&Path>`.
+
+use std::path::{Path, PathBuf};
+
+/// A command of a command-line tool.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(path_accessors)]
+pub enum Command {
+    Convert {
+        input: PathBuf,
+        output: std::path::PathBuf,
+    },
+
+    Check {
+        input: PathBuf,
+        strict: bool,
+    },
+}
+
+fn main() {
+    let convert = Command::Convert {
+        input: "image.png".into(),
+        output: "image.jpg".into(),
+    };
+
+    let check = Command::Check {
+        input: "image.png".into(),
+        strict: true,
+    };
+
+    assert_eq!(convert.input_path(), Path::new("image.png"));
+    assert_eq!(convert.output_path(), Some(Path::new("image.jpg")));
+    assert_eq!(check.output_path(), None);
+    assert_eq!(check.input_path().extension(), Some("png".as_ref()));
+}
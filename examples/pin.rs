@@ -0,0 +1,62 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Task`] with a structurally pinned
+//! field, which is annotated with `#[enum_fields(pin)]`.
+//!
+//! The `counter` is a [`Counter`], which can't be moved once it's pinned. The
+//! mutable getter `Task::counter_mut()` therefore takes a `Pin<&mut Task>` and
+//! returns a `Pin<&mut Counter>`, instead of taking and returning a `&mut`.
+//! Since the derive can't check that the field is structurally pinned, the
+//! mutable getter is `unsafe`.
+
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+
+/// A counter which must not be moved once it's pinned.
+#[derive(Debug, Default)]
+pub struct Counter {
+    count: u32,
+    _pinned: PhantomPinned,
+}
+
+impl Counter {
+    pub fn increment(self: Pin<&mut Self>) {
+        // SAFETY: `count` isn't structurally pinned, so it may be moved.
+        unsafe { self.get_unchecked_mut().count += 1 };
+    }
+}
+
+/// A task that is either running and counting, or done.
+#[derive(Debug, enum_fields::EnumFields)]
+pub enum Task {
+    Running {
+        name: String,
+
+        #[enum_fields(pin)]
+        counter: Counter,
+    },
+
+    Done {
+        name: String,
+    },
+}
+
+fn main() {
+    let mut task = Box::pin(Task::Running {
+        name: "count".into(),
+        counter: Counter::default(),
+    });
+
+    // SAFETY: `Task` doesn't move `counter` out while it's pinned, and is only
+    // `Unpin` when `Counter` is, which it isn't.
+    if let Some(counter) = unsafe { task.as_mut().counter_mut() } {
+        counter.increment();
+    }
+    assert_eq!(task.counter().map(|counter| counter.count), Some(1));
+    assert_eq!(task.name(), "count");
+
+    let mut done = Box::pin(Task::Done { name: "done".into() });
+    // SAFETY: See above.
+    assert!(unsafe { done.as_mut().counter_mut() }.is_none());
+}
@@ -0,0 +1,57 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Request`], with variants of
+//! every shape: struct, tuple and unit variants.
+//!
+//! For each variant, [`enum_fields::EnumFields`] generates a predicate named
+//! after the variant in `snake_case`, e.g. `Request::is_http_get()` for the
+//! `HTTPGet` variant. The name of the variant itself is returned by
+//! `Request::variant_name()`, and the number of its fields by
+//! `Request::field_count()`.
+
+/// A request sent to a server.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Request {
+    HTTPGet {
+        path: String,
+    },
+
+    WebSocketFrame(Vec<u8>),
+
+    Redirect(u16, String),
+
+    Ping,
+
+    Version2Hello,
+}
+
+fn main() {
+    let get = Request::HTTPGet { path: "/".into() };
+    let frame = Request::WebSocketFrame(vec![1, 2, 3]);
+    let ping = Request::Ping;
+    let redirect = Request::Redirect(301, "/home".into());
+
+    assert!(get.is_http_get());
+    assert!(!get.is_ping());
+
+    assert!(frame.is_web_socket_frame());
+    assert!(!frame.is_http_get());
+
+    assert!(ping.is_ping());
+    assert!(Request::Version2Hello.is_version2_hello());
+
+    assert_eq!(get.variant_name(), "HTTPGet");
+    assert_eq!(frame.variant_name(), "WebSocketFrame");
+    assert_eq!(ping.variant_name(), "Ping");
+
+    // Only struct variants have named fields.
+    assert_eq!(get.field_names(), ["path"]);
+    assert!(frame.field_names().is_empty());
+    assert!(ping.field_names().is_empty());
+
+    assert_eq!(get.field_count(), 1);
+    assert_eq!(frame.field_count(), 1);
+    assert_eq!(ping.field_count(), 0);
+    assert_eq!(redirect.field_count(), 2);
+}
@@ -0,0 +1,69 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Resource`] with a field that is
+//! annotated with `#[enum_fields(raw_ptr)]`, as used for FFI.
+//!
+//! Next to the regular getters, `Resource::handle_ptr()` returns a
+//! `*const Handle` and `Resource::handle_ptr_mut()` a `*mut Handle`, which
+//! are null for a `Closed` resource, since it doesn't have a `handle`. The
+//! `handle` of a `Shared` resource is annotated with `no_mut`, so its mutable
+//! pointer is null as well.
+
+/// A handle passed to a C library.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Handle {
+    id: u32,
+}
+
+/// A stand-in for a function of a C library, incrementing the handle.
+///
+/// # Safety
+/// The pointer must be valid for writes, or null.
+unsafe extern "C" fn increment(handle: *mut Handle) {
+    // SAFETY: The caller guarantees the pointer is either valid or null.
+    if let Some(handle) = unsafe { handle.as_mut() } {
+        handle.id += 1;
+    }
+}
+
+/// A resource that is either open or closed.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Resource {
+    Open {
+        #[enum_fields(raw_ptr)]
+        handle: Handle,
+    },
+
+    Shared {
+        #[enum_fields(no_mut)]
+        handle: Handle,
+    },
+
+    Closed,
+}
+
+fn main() {
+    let mut open = Resource::Open { handle: Handle { id: 1 } };
+    let mut closed = Resource::Closed;
+
+    let pointer = open.handle_ptr();
+    assert!(!pointer.is_null());
+    // SAFETY: `open` has a `handle`, and isn't modified while it is read.
+    assert_eq!(unsafe { *pointer }, Handle { id: 1 });
+
+    // SAFETY: The pointers are either valid or null.
+    unsafe {
+        increment(open.handle_ptr_mut());
+        increment(closed.handle_ptr_mut());
+    }
+    assert_eq!(open.handle(), Some(&Handle { id: 2 }));
+
+    assert!(closed.handle_ptr().is_null());
+    assert!(closed.handle_ptr_mut().is_null());
+
+    let mut shared = Resource::Shared { handle: Handle { id: 3 } };
+    assert!(!shared.handle_ptr().is_null());
+    assert!(shared.handle_ptr_mut().is_null());
+}
@@ -0,0 +1,87 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases read-only fields.
+//!
+//! The enum [`Version`] is annotated with `#[enum_fields(readonly)]`, so it
+//! only has the getters `Version::number()` and `Version::label()`, without
+//! `Version::number_mut()` or `Version::label_mut()`.
+//!
+//! The `id` field of [`Record`] is annotated with `#[enum_fields(readonly)]`
+//! as well, so `Record::id_mut()` isn't generated, while `Record::title_mut()`
+//! is. The `owner` field is annotated with `#[enum_fields(no_mut)]`, so it
+//! doesn't have a `Record::owner_mut()` either, but it does have a setter.
+//! Lastly, the `notes` field is only annotated with `#[enum_fields(no_mut)]`
+//! in the `Film` variant, so `Record::notes_mut()` returns an
+//! `Option<&mut String>`, which is `None` for films.
+
+use std::rc::Rc;
+
+/// An immutable version, of which the fields can't be changed through the
+/// getters.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(readonly, setters)]
+pub enum Version {
+    Release {
+        number: u32,
+    },
+
+    Preview {
+        number: u32,
+        label: String,
+    },
+}
+
+/// A record of which the identifier can't be changed after its creation.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(setters)]
+pub enum Record {
+    Book {
+        #[enum_fields(readonly)]
+        id: u64,
+        title: String,
+
+        #[enum_fields(no_mut)]
+        owner: Rc<str>,
+        notes: String,
+    },
+
+    Film {
+        id: u64,
+        title: String,
+
+        #[enum_fields(no_mut)]
+        owner: Rc<str>,
+
+        #[enum_fields(no_mut)]
+        notes: String,
+    },
+}
+
+fn main() {
+    let release = Version::Release { number: 3 };
+    let preview = Version::Preview { number: 4, label: "beta".into() };
+
+    assert_eq!(release.number(), &3);
+    assert_eq!(release.label(), None);
+    assert_eq!(preview.label(), Some(&"beta".into()));
+
+    let owner: Rc<str> = "library".into();
+    let mut book = Record::Book { id: 7, title: "Dune".into(), owner: owner.clone(), notes: String::new() };
+    assert_eq!(book.id(), &7);
+
+    *book.title_mut() = "Dune Messiah".into();
+    assert_eq!(book.title(), "Dune Messiah");
+    assert_eq!(book, Record::Book { id: 7, title: "Dune Messiah".into(), owner: owner.clone(), notes: String::new() });
+
+    book.set_owner("archive".into());
+    assert_eq!(&**book.owner(), "archive");
+
+    book.notes_mut().unwrap().push_str("Signed copy");
+    assert_eq!(book.notes(), "Signed copy");
+
+    let mut film = Record::Film { id: 8, title: "Dune".into(), owner, notes: "Remastered".into() };
+    assert_eq!(film.notes_mut(), None);
+    film.set_notes("Director's cut".into());
+    assert_eq!(film.notes(), "Director's cut");
+}
@@ -0,0 +1,75 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases enums deriving
+//! [`enum_fields::EnumFields`] in a submodule, which are re-exported and used
+//! from the parent module.
+//!
+//! The generated methods match the variants using `Self`, so they don't
+//! depend on the path the enum is used by, and are `pub` by default, so they
+//! can be called through the re-export. The generic [`Node`] is re-exported
+//! under another name as well.
+
+mod model {
+    pub mod entity {
+        /// An entity that can be either a `Company` or a `Person`.
+        #[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+        #[enum_fields(setters, trait_name = "EntityFields")]
+        pub enum Entity {
+            Company {
+                name: String,
+                ceo: String,
+            },
+
+            Person {
+                name: String,
+            },
+        }
+
+        /// A node of a tree containing values of type `T`.
+        #[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+        #[enum_fields(inherit_vis)]
+        pub(crate) enum Node<T> {
+            Leaf {
+                value: T,
+            },
+
+            Branch {
+                value: T,
+                children: Vec<Node<T>>,
+            },
+        }
+    }
+
+    pub use self::entity::{Entity, EntityFields};
+    pub(crate) use self::entity::Node as Tree;
+}
+
+use model::{Entity, EntityFields, Tree};
+
+fn name_of(entity: &impl EntityFields) -> &String {
+    entity.name()
+}
+
+fn main() {
+    let mut company = Entity::Company {
+        name: "Apple".into(),
+        ceo: "Tim Cook".into(),
+    };
+
+    assert!(company.is_company());
+    assert_eq!(company.ceo(), Some(&"Tim Cook".into()));
+    company.set_name("Microsoft".into());
+    assert_eq!(name_of(&company), "Microsoft");
+
+    let person = Entity::Person { name: "Tim Berners-Lee".into() };
+    assert_eq!(person.ceo(), None);
+
+    let tree = Tree::Branch {
+        value: 1,
+        children: vec![Tree::Leaf { value: 2 }],
+    };
+    assert_eq!(tree.value(), &1);
+    assert_eq!(tree.children().map(Vec::len), Some(1));
+    assert_eq!(model::entity::Node::<u8>::VARIANTS, ["Leaf", "Branch"]);
+}
@@ -0,0 +1,54 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Token`] borrowing its text from
+//! the source it was read from.
+//!
+//! Since the `text` field is a `&'a str`, `Token::text()` returns the
+//! `&'a str` itself instead of a `&&'a str`, so the text outlives the token.
+
+/// A token of a source text, borrowing from that text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Token<'a> {
+    Word {
+        text: &'a str,
+    },
+
+    Number {
+        text: &'a str,
+        value: i64,
+    },
+
+    Quoted(&'a str, char),
+}
+
+/// Returns the text of the token, with the lifetime of the source instead of
+/// the one of the token.
+fn text<'a>(token: &Token<'a>) -> Option<&'a str> {
+    token.text()
+}
+
+fn main() {
+    let source = String::from("answer 42 'quote'");
+
+    let texts: Vec<&str> = source.split(' ')
+        .map(|text| match text.parse() {
+            Ok(value) => Token::Number { text, value },
+            Err(..) if text.starts_with('\'') => Token::Quoted(text, '\''),
+            Err(..) => Token::Word { text },
+        })
+        .filter_map(|token| text(&token))
+        .collect();
+    assert_eq!(texts, ["answer", "42"]);
+
+    let quoted = Token::Quoted("'quote'", '\'');
+    let literal: Option<&str> = quoted.field_0();
+    assert_eq!(literal, Some("'quote'"));
+    assert_eq!(quoted.field_1(), Some(&'\''));
+
+    let mut word = Token::Word { text: "answer" };
+    if let Some(text) = word.text_mut() {
+        *text = "question";
+    }
+    assert_eq!(word.text(), Some("question"));
+}
@@ -0,0 +1,79 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`], of which the `name`
+//! field would clash with the hand-written `Entity::name()` method.
+//!
+//! Using `#[enum_fields(rename = "display_name")]`, the getters are named
+//! `Entity::display_name()` and `Entity::display_name_mut()` instead.
+//!
+//! Renaming also works for the positional fields of tuple variants, which
+//! makes `Version::major()` available instead of `Version::field_0()`.
+//!
+//! A field can be renamed to a keyword in its raw form as well: the `kind`
+//! field of a [`Token`] gets the getters `Token::r#type()` and
+//! `Token::type_mut()`.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Entity {
+    Company {
+        #[enum_fields(rename = "display_name")]
+        name: String,
+    },
+
+    Person {
+        #[enum_fields(rename = "display_name")]
+        name: String,
+
+        #[enum_fields(rename = "years")]
+        age: u8,
+    },
+}
+
+impl Entity {
+    /// The name of the entity, including its kind.
+    pub fn name(&self) -> String {
+        match self {
+            Self::Company { name } => format!("company {name}"),
+            Self::Person { name, .. } => format!("person {name}"),
+        }
+    }
+}
+
+/// A version, either as a full version or only the major version.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Version {
+    Full(#[enum_fields(rename = "major")] u32, u32, u32),
+    Short(#[enum_fields(rename = "major")] u32),
+}
+
+/// A token of which the kind is exposed as its type.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Token {
+    Ident {
+        #[enum_fields(rename = "r#type")]
+        kind: String,
+    },
+}
+
+fn main() {
+    let mut company = Entity::Company { name: "Apple".into() };
+    let person = Entity::Person { name: "Ada".into(), age: 36 };
+
+    assert_eq!(company.display_name(), "Apple");
+    assert_eq!(company.name(), "company Apple");
+    assert_eq!(person.years(), Some(&36));
+
+    *company.display_name_mut() = "Microsoft".into();
+    assert_eq!(company.display_name(), "Microsoft");
+
+    let version = Version::Full(1, 2, 3);
+    assert_eq!(*version.major(), 1);
+    assert_eq!(*Version::Short(4).major(), 4);
+    assert_eq!(version.field_1(), Some(&2));
+
+    let mut token = Token::Ident { kind: "keyword".into() };
+    *token.type_mut() = "identifier".into();
+    assert_eq!(token.r#type(), "identifier");
+}
@@ -0,0 +1,46 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`] annotated with
+//! `#[enum_fields(replace)]`.
+//!
+//! Every variant has a `name`, so `Entity::replace_name()` returns the previous
+//! `String` directly. Only `Company` has a `ceo`, so `Entity::replace_ceo()`
+//! returns an `Option`, which is `None` for a `Person`, leaving it unchanged.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(replace)]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+fn main() {
+    let mut company = Entity::Company {
+        name: "Google".into(),
+        ceo: "Larry Page".into(),
+    };
+
+    let mut person = Entity::Person {
+        name: "Tim Berners-Lee".into(),
+    };
+
+    assert_eq!(company.replace_name("Alphabet".into()), "Google");
+    assert_eq!(company.name(), "Alphabet");
+
+    assert_eq!(company.replace_ceo("Sundar Pichai".into()), Some("Larry Page".into()));
+    assert_eq!(company.ceo(), Some(&"Sundar Pichai".into()));
+
+    assert_eq!(person.replace_name("Sir Tim Berners-Lee".into()), "Tim Berners-Lee");
+
+    // A `Person` doesn't have a `ceo`, so the new value isn't stored.
+    assert_eq!(person.replace_ceo("Nobody".into()), None);
+    assert_eq!(person, Entity::Person { name: "Sir Tim Berners-Lee".into() });
+}
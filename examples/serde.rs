@@ -0,0 +1,50 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Message`] deriving both
+//! [`enum_fields::EnumFields`] and the traits of [`serde`].
+//!
+//! The derive only reads the `#[enum_fields(...)]` attributes and leaves any
+//! other attribute intact, so the `#[serde(...)]` attributes on the enum and
+//! its fields still apply, even on fields that are also annotated with
+//! `#[enum_fields(...)]`.
+
+use serde::{Deserialize, Serialize};
+
+/// A message sent between a client and a server.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, enum_fields::EnumFields)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[enum_fields(setters)]
+pub enum Message {
+    Request {
+        #[serde(rename = "requestId")]
+        id: u64,
+        method: String,
+    },
+
+    Response {
+        #[serde(rename = "requestId")]
+        id: u64,
+
+        #[enum_fields(rename = "result")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        body: Option<String>,
+    },
+}
+
+fn main() {
+    let mut request = Message::Request { id: 1, method: "ping".into() };
+    assert_eq!(request.id(), &1);
+    assert_eq!(request.method(), Some(&"ping".into()));
+
+    request.set_id(2);
+    assert_eq!(
+        serde_json::to_string(&request).unwrap(),
+        r#"{"type":"request","requestId":2,"method":"ping"}"#,
+    );
+
+    let response: Message = serde_json::from_str(r#"{"type":"response","requestId":2}"#).unwrap();
+    assert_eq!(response.id(), &2);
+    assert_eq!(response.result(), Some(&None));
+    assert!(response.is_response());
+}
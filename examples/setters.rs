@@ -0,0 +1,60 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`], which is annotated with
+//! `#[enum_fields(setters)]`.
+//!
+//! This generates a setter for every field: `Entity::set_name()` and
+//! `Entity::set_ceo()`. Since only `Company` has a `ceo` field,
+//! `Entity::set_ceo()` returns whether the value was actually set.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(setters)]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+/// A setting, of which a field is named like the parameter of the setter.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(setters)]
+pub enum Setting {
+    Number(i64),
+
+    Text {
+        value: String,
+    },
+}
+
+fn main() {
+    let mut company = Entity::Company {
+        name: "Apple".into(),
+        ceo: "Tim Cook".into(),
+    };
+
+    let mut person = Entity::Person {
+        name: "Tim Berners-Lee".into(),
+    };
+
+    company.set_name("Microsoft".into());
+    assert_eq!(company.name(), "Microsoft");
+
+    assert!(company.set_ceo("Satya Nadella".into()));
+    assert_eq!(company.ceo(), Some(&"Satya Nadella".into()));
+
+    // A `Person` doesn't have a `ceo`, so it is left unchanged.
+    assert!(!person.set_ceo("Nobody".into()));
+    assert_eq!(person, Entity::Person { name: "Tim Berners-Lee".into() });
+
+    let mut setting = Setting::Text { value: "on".into() };
+    assert!(setting.set_value("off".into()));
+    assert!(!setting.set_field_0(1));
+    assert_eq!(setting.value(), Some(&"off".into()));
+}
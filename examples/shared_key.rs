@@ -0,0 +1,45 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Record`] annotated with
+//! `#[enum_fields(shared_key)]`.
+//!
+//! The `tenant` and `id` fields are present in every variant, so
+//! `Record::shared_key()` returns a `(&u32, &u64)`, which is used as the key
+//! of a [`HashMap`]. The `name` field is only present in `User`, so it isn't
+//! part of the key.
+
+use std::collections::HashMap;
+
+/// A record stored for a tenant.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(shared_key)]
+pub enum Record {
+    User {
+        tenant: u32,
+        id: u64,
+        name: String,
+    },
+
+    Group {
+        tenant: u32,
+        id: u64,
+    },
+}
+
+fn main() {
+    let records = [
+        Record::User { tenant: 1, id: 10, name: "Alice".into() },
+        Record::Group { tenant: 1, id: 20 },
+        Record::User { tenant: 2, id: 10, name: "Bob".into() },
+    ];
+
+    let index: HashMap<(&u32, &u64), &Record> = records.iter()
+        .map(|record| (record.shared_key(), record))
+        .collect();
+
+    assert_eq!(records[0].shared_key(), (&1, &10));
+    assert_eq!(index.len(), 3);
+    assert_eq!(index[&(&2, &10)].name(), Some(&"Bob".into()));
+    assert!(index[&(&1, &20)].is_group());
+}
@@ -61,4 +61,16 @@ fn main() {
 
     *company.name_mut() = "Microsoft".into();
     assert_eq!(company.name(), "Microsoft");
+
+    // Every variant gets a predicate, named after the variant.
+    assert!(company.is_company());
+    assert!(!company.is_person());
+    assert!(person.is_person());
+
+    // The names of the fields of the active variant, in declaration order.
+    assert_eq!(company.field_names(), ["name", "ceo"]);
+    assert_eq!(person.field_names(), ["name"]);
+
+    // The names of all variants, in declaration order.
+    assert_eq!(Entity::VARIANTS, ["Company", "Person"]);
 }
@@ -0,0 +1,67 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases enums with a single variant, of every
+//! shape, as well as an enum without any variants.
+//!
+//! Since the only variant has every field, the getters return the fields
+//! directly, without any `None` arms. An enum without variants doesn't get any
+//! methods, but it still derives [`enum_fields::EnumFields`], along with an
+//! empty companion trait.
+
+/// A wrapper with a single struct variant.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(as_variant, try)]
+pub enum Wrapper {
+    Named {
+        name: String,
+        value: u32,
+    },
+}
+
+/// A wrapper with a single tuple variant.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Pair {
+    Both(u8, char),
+}
+
+/// An enum with a single unit variant.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Unit {
+    Only,
+}
+
+/// An enum without any variants, which can't be constructed.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(trait_name = "NeverFields")]
+pub enum Never {}
+
+/// Accepts any value implementing the empty companion trait of [`Never`].
+fn accepts_never(_: Option<&impl NeverFields>) -> bool {
+    true
+}
+
+fn main() {
+    let mut wrapper = Wrapper::Named { name: "answer".into(), value: 42 };
+    assert_eq!(wrapper.name(), "answer");
+    assert_eq!(*wrapper.value(), 42);
+    assert!(wrapper.is_named());
+    assert_eq!(wrapper.as_named(), Some((&"answer".into(), &42)));
+    assert_eq!(wrapper.field_names(), ["name", "value"]);
+
+    *wrapper.value_mut() += 1;
+    assert_eq!(*wrapper.value(), 43);
+
+    let pair = Pair::Both(1, 'a');
+    let first: &u8 = pair.field_0();
+    assert_eq!(*first, 1);
+    assert_eq!(*pair.field_1(), 'a');
+    assert_eq!(pair.variant_name(), "Both");
+
+    let unit = Unit::Only;
+    assert!(unit.is_only());
+    assert!(unit.field_names().is_empty());
+
+    let nothing: Option<Never> = None;
+    assert!(accepts_never(nothing.as_ref()));
+}
@@ -0,0 +1,60 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Node`], of which the `Cached`
+//! variant contains an internal `cache` field.
+//!
+//! Since `cache` is annotated with `#[enum_fields(skip)]`, no `Node::cache()`
+//! getter is generated for it.
+//!
+//! The `name` field is also skipped on the `Comment` variant, which makes
+//! `Node::name()` return an `Option<&String>`, even though every variant
+//! contains a `name` field.
+
+use std::cell::RefCell;
+
+/// A node in a document tree.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Node {
+    Element {
+        name: String,
+    },
+
+    Cached {
+        name: String,
+
+        #[enum_fields(skip)]
+        cache: RefCell<Vec<u8>>,
+    },
+
+    Comment {
+        #[enum_fields(skip)]
+        name: String,
+    },
+}
+
+impl Node {
+    /// A hand-written method that would clash with a generated `cache()`.
+    pub fn cache(&self) -> Option<usize> {
+        match self {
+            Self::Cached { cache, .. } => Some(cache.borrow().len()),
+            _ => None,
+        }
+    }
+}
+
+fn main() {
+    let element = Node::Element { name: "div".into() };
+    let cached = Node::Cached {
+        name: "img".into(),
+        cache: RefCell::new(vec![0xFF, 0xD8]),
+    };
+    let comment = Node::Comment { name: "todo".into() };
+
+    assert_eq!(element.name(), Some(&"div".into()));
+    assert_eq!(cached.name(), Some(&"img".into()));
+    assert_eq!(comment.name(), None);
+
+    assert_eq!(cached.cache(), Some(2));
+    assert_eq!(element.cache(), None);
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`] annotated with
+//! `#[enum_fields(split_mut)]`.
+//!
+//! The `name` and `id` fields are present in every variant, so
+//! `Entity::fields_mut()` returns a `(&mut String, &mut u32)`, which can both
+//! be used at the same time. The `ceo` is only present in `Company`, and the
+//! `created` field is read-only, so neither is included.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(split_mut)]
+pub enum Entity {
+    Company {
+        name: String,
+        id: u32,
+        ceo: String,
+        #[enum_fields(readonly)]
+        created: u16,
+    },
+
+    Person {
+        id: u32,
+        name: String,
+        #[enum_fields(readonly)]
+        created: u16,
+    },
+}
+
+/// Renames the entity, keeping track of the number of revisions in its id.
+fn rename(entity: &mut Entity, new_name: &str) {
+    let (name, id) = entity.fields_mut();
+    name.clear();
+    name.push_str(new_name);
+    *id += 1;
+}
+
+fn main() {
+    let mut company = Entity::Company {
+        name: "Apple".into(),
+        id: 1,
+        ceo: "Tim Cook".into(),
+        created: 1976,
+    };
+
+    let mut person = Entity::Person {
+        id: 7,
+        name: "Tim Berners-Lee".into(),
+        created: 1955,
+    };
+
+    let (name, id) = company.fields_mut();
+    name.push_str(" Inc.");
+    *id *= 10;
+    assert_eq!(company.name(), "Apple Inc.");
+    assert_eq!(company.id(), &10);
+
+    rename(&mut person, "Sir Tim Berners-Lee");
+    assert_eq!(person, Entity::Person {
+        id: 8,
+        name: "Sir Tim Berners-Lee".into(),
+        created: 1955,
+    });
+}
@@ -0,0 +1,46 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`], which is annotated with
+//! `#[enum_fields(str_accessors)]`.
+//!
+//! For every `String` field, this generates an additional getter returning a
+//! string slice: `Entity::name_str()` returns a `&str`, and
+//! `Entity::ceo_str()` an `Option<&str>`. The `founded` field isn't a
+//! `String`, so it doesn't get such a getter.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(str_accessors)]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: std::string::String,
+        founded: u16,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+fn main() {
+    let company = Entity::Company {
+        name: "Apple".into(),
+        ceo: "Tim Cook".into(),
+        founded: 1976,
+    };
+
+    let person = Entity::Person {
+        name: "Tim Berners-Lee".into(),
+    };
+
+    let name: &str = company.name_str();
+    assert_eq!(name, "Apple");
+    assert_eq!(person.name_str(), "Tim Berners-Lee");
+
+    assert_eq!(company.ceo_str(), Some("Tim Cook"));
+    assert_eq!(person.ceo_str(), None);
+
+    assert_eq!(company.founded(), Some(&1976));
+}
@@ -0,0 +1,53 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`] annotated with
+//! `#[enum_fields(swap)]`.
+//!
+//! Every variant has a `name`, so `Entity::swap_name()` always swaps the names
+//! of two entities. Only `Company` has a `ceo`, so `Entity::swap_ceo()` only
+//! swaps them between two companies, returning whether it did.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(swap)]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+fn main() {
+    let mut apple = Entity::Company {
+        name: "Apple".into(),
+        ceo: "Tim Cook".into(),
+    };
+
+    let mut microsoft = Entity::Company {
+        name: "Microsoft".into(),
+        ceo: "Satya Nadella".into(),
+    };
+
+    let mut person = Entity::Person {
+        name: "Tim Berners-Lee".into(),
+    };
+
+    apple.swap_name(&mut person);
+    assert_eq!(apple.name(), "Tim Berners-Lee");
+    assert_eq!(person.name(), "Apple");
+
+    assert!(apple.swap_ceo(&mut microsoft));
+    assert_eq!(apple.ceo(), Some(&"Satya Nadella".into()));
+    assert_eq!(microsoft.ceo(), Some(&"Tim Cook".into()));
+
+    // A `Person` doesn't have a `ceo`, so nothing is swapped.
+    assert!(!apple.swap_ceo(&mut person));
+    assert!(!person.swap_ceo(&mut microsoft));
+    assert_eq!(apple.ceo(), Some(&"Satya Nadella".into()));
+    assert_eq!(person, Entity::Person { name: "Apple".into() });
+}
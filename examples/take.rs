@@ -0,0 +1,77 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`] annotated with
+//! `#[enum_fields(take)]`.
+//!
+//! `Entity::take_name()` moves the `name` out of every variant, leaving an
+//! empty `String` behind. Only `Company` has a `ceo`, so `Entity::take_ceo()`
+//! returns an `Option`, which is `None` for a `Person`.
+//!
+//! The `callback` of a [`Task`] can't be taken, since a boxed closure doesn't
+//! implement `Default`, but its `label` still can.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(take)]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+/// A task to run, of which the callback doesn't have a default.
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(take)]
+pub enum Task {
+    Once {
+        label: String,
+        callback: Box<dyn Fn() -> u32>,
+    },
+
+    Repeat {
+        label: String,
+        callback: Box<dyn Fn() -> u32>,
+        times: usize,
+    },
+}
+
+fn main() {
+    let mut company = Entity::Company {
+        name: "Microsoft".into(),
+        ceo: "Satya Nadella".into(),
+    };
+
+    let mut person = Entity::Person {
+        name: "Tim Berners-Lee".into(),
+    };
+
+    assert_eq!(company.take_name(), "Microsoft");
+    assert_eq!(company.name(), "");
+
+    assert_eq!(company.take_ceo(), Some("Satya Nadella".into()));
+    assert_eq!(company.ceo(), Some(&String::new()));
+
+    // Taking again returns the default that was left behind.
+    assert_eq!(company.take_ceo(), Some(String::new()));
+
+    assert_eq!(person.take_ceo(), None);
+    assert_eq!(person, Entity::Person { name: "Tim Berners-Lee".into() });
+
+    let mut task = Task::Repeat {
+        label: "count".into(),
+        callback: Box::new(|| 42),
+        times: 3,
+    };
+
+    assert_eq!(task.take_label(), "count");
+    assert_eq!(task.label(), "");
+    assert_eq!(task.take_times(), Some(3));
+    assert_eq!(task.times(), Some(&0));
+    assert_eq!((task.callback())(), 42);
+}
@@ -0,0 +1,90 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`], which is annotated with
+//! `#[enum_fields(trait_name = "EntityFields")]`.
+//!
+//! This generates the [`EntityFields`] trait containing the getters of
+//! [`Entity`], which is then implemented by it. Functions can use this trait
+//! as a bound, which also allows other types to be used with them, such as
+//! [`Robot`].
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(trait_name = "EntityFields")]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+/// A robot, implementing the same fields by hand.
+pub struct Robot {
+    name: String,
+}
+
+impl EntityFields for Robot {
+    fn name(&self) -> &String {
+        &self.name
+    }
+
+    fn ceo(&self) -> Option<&String> {
+        None
+    }
+}
+
+/// A generic tree, whose trait takes the same generic parameters.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(trait_name = "TreeFields")]
+pub enum Tree<T: Clone> {
+    Leaf {
+        value: T,
+    },
+
+    Node {
+        value: T,
+        children: Vec<Tree<T>>,
+    },
+}
+
+fn describe(entity: &impl EntityFields) -> String {
+    match entity.ceo() {
+        Some(ceo) => format!("{} (led by {ceo})", entity.name()),
+        None => entity.name().clone(),
+    }
+}
+
+fn sum<T: Clone + Into<i64>>(tree: &impl TreeFields<T>) -> i64 {
+    tree.value().clone().into()
+        + tree.children().map_or(0, |children| children.iter().map(sum).sum())
+}
+
+fn main() {
+    let company = Entity::Company {
+        name: "Apple".into(),
+        ceo: "Tim Cook".into(),
+    };
+
+    let person = Entity::Person {
+        name: "Tim Berners-Lee".into(),
+    };
+
+    let robot = Robot {
+        name: "R2-D2".into(),
+    };
+
+    assert_eq!(describe(&company), "Apple (led by Tim Cook)");
+    assert_eq!(describe(&person), "Tim Berners-Lee");
+    assert_eq!(describe(&robot), "R2-D2");
+
+    let tree = Tree::Node {
+        value: 1,
+        children: vec![Tree::Leaf { value: 2 }, Tree::Leaf { value: 3 }],
+    };
+    assert_eq!(sum(&tree), 6);
+}
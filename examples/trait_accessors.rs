@@ -0,0 +1,52 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases the `trait-accessors` feature, which
+//! generates a companion trait for every enum deriving
+//! [`enum_fields::EnumFields`].
+//!
+//! The enum [`Entity`] isn't annotated with `#[enum_fields(trait_name = "...")]`,
+//! but still gets the trait `EntityFields` containing its getters, which is
+//! implemented by it. The getter of the `revenue` field is only visible in
+//! this crate, so it is left out of the trait.
+//!
+//! Run with `cargo run --example trait_accessors --features trait-accessors`.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+
+        #[enum_fields(vis = "pub(crate)")]
+        revenue: u64,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+fn describe(entity: &impl EntityFields) -> String {
+    match entity.ceo() {
+        Some(ceo) => format!("{} (led by {ceo})", entity.name()),
+        None => entity.name().clone(),
+    }
+}
+
+fn main() {
+    let company = Entity::Company {
+        name: "Apple".into(),
+        ceo: "Tim Cook".into(),
+        revenue: 383_000_000_000,
+    };
+
+    let person = Entity::Person {
+        name: "Tim Berners-Lee".into(),
+    };
+
+    assert_eq!(describe(&company), "Apple (led by Tim Cook)");
+    assert_eq!(describe(&person), "Tim Berners-Lee");
+    assert_eq!(company.revenue(), Some(&383_000_000_000));
+}
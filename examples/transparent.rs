@@ -0,0 +1,53 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases enums annotated with
+//! `#[enum_fields(transparent)]`, which implement `Deref` to the single field
+//! of their variants.
+//!
+//! Every variant of [`Name`] wraps a `String`, so a `Name` can be used like a
+//! `str`. The variants of [`Label`] hold a named field `text` instead.
+
+/// A name of a person, tagged with where it came from.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(transparent)]
+pub enum Name {
+    Given(String),
+    Nickname(String),
+    Alias(String),
+}
+
+/// A label in one of the languages of a user interface.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(transparent)]
+pub enum Label {
+    English {
+        text: String,
+    },
+
+    Dutch {
+        text: String,
+    },
+}
+
+fn print_length(text: &str) -> usize {
+    text.len()
+}
+
+fn main() {
+    let given = Name::Given("Timothy".into());
+    let nickname = Name::Nickname("Tim".into());
+
+    assert_eq!(*given, "Timothy");
+    assert_eq!(nickname.len(), 3);
+    assert!(Name::Alias("TimBL".into()).starts_with("Tim"));
+
+    // Deref coercion applies as well.
+    assert_eq!(print_length(&given), 7);
+
+    let label = Label::Dutch {
+        text: "Hallo".into(),
+    };
+    assert_eq!(label.to_uppercase(), "HALLO");
+    assert_eq!(*Label::English { text: "Hello".into() }, "Hello");
+}
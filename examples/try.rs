@@ -0,0 +1,43 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`], which is annotated with
+//! `#[enum_fields(try)]`.
+//!
+//! Since only `Company` has a `ceo` field, this generates `Entity::try_ceo()`,
+//! which returns a `Result<&String, EnumFieldError>`. The error describes which
+//! variant didn't have the field. The `name` field is present in every
+//! variant, so it can't fail and doesn't get such a getter.
+
+use enum_fields::EnumFieldError;
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(try)]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+fn main() {
+    let company = Entity::Company {
+        name: "Apple".into(),
+        ceo: "Tim Cook".into(),
+    };
+
+    let person = Entity::Person {
+        name: "Tim Berners-Lee".into(),
+    };
+
+    assert_eq!(company.try_ceo(), Ok(&"Tim Cook".into()));
+
+    let error = person.try_ceo().unwrap_err();
+    assert_eq!(error, EnumFieldError { field: "ceo", variant: "Person" });
+    assert_eq!(error.to_string(), "variant `Person` doesn't have the field `ceo`");
+}
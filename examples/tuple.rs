@@ -0,0 +1,58 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Address`], which mixes tuple
+//! variants with a struct variant.
+//!
+//! Fields of tuple variants don't have a name, so [`enum_fields::EnumFields`]
+//! generates positional getters for them: `Address::field_0()`,
+//! `Address::field_1()`, etc.
+//!
+//! Positional fields are keyed on both their position and their type. Since
+//! `V4` and `Named` have differently typed fields at position `0`, only the
+//! first variant (`V4`) participates in `Address::field_0()`.
+
+/// An address that is either an IPv4 address or a host name.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Address {
+    V4(u8, u8, u8, u8),
+
+    Named(String),
+
+    Unix {
+        path: String,
+    },
+}
+
+/// A color, either as a hex code or as RGB components.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Color {
+    Hex(u32),
+    Rgb(u32, u8, u8),
+}
+
+fn main() {
+    let mut localhost = Address::V4(127, 0, 0, 1);
+    let named = Address::Named("localhost".into());
+    let socket = Address::Unix { path: "/tmp/socket".into() };
+
+    assert_eq!(localhost.field_0(), Some(&127));
+    assert_eq!(localhost.field_3(), Some(&1));
+    assert_eq!(named.field_0(), None);
+    assert_eq!(socket.field_0(), None);
+    assert_eq!(socket.path(), Some(&"/tmp/socket".into()));
+
+    if let Some(last) = localhost.field_3_mut() {
+        *last = 2;
+    }
+    assert_eq!(localhost, Address::V4(127, 0, 0, 2));
+
+    // Both variants of `Color` have a `u32` at position `0`, so that getter
+    // returns the type directly.
+    let mut color = Color::Rgb(0, 255, 0);
+    assert_eq!(*Color::Hex(0xFF00FF).field_0(), 0xFF00FF);
+    assert_eq!(color.field_1(), Some(&255));
+
+    *color.field_0_mut() = 0xFF;
+    assert_eq!(color, Color::Rgb(0xFF, 255, 0));
+}
@@ -0,0 +1,45 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases overriding the type returned by the
+//! getters of a field.
+//!
+//! The `payload` field of [`Packet`] is a `Vec<u8>`, but it is annotated with
+//! `#[enum_fields(ty = "[u8]")]`, so `Packet::payload()` returns a `&[u8]`
+//! and `Packet::payload_mut()` a `&mut [u8]`. Likewise, `Packet::route()`
+//! returns an `Option<&str>` for the `String` field `route`.
+
+/// A packet sent over a network.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Packet {
+    Data {
+        #[enum_fields(ty = "[u8]")]
+        payload: Vec<u8>,
+
+        #[enum_fields(ty = "str")]
+        route: String,
+    },
+
+    Control {
+        #[enum_fields(ty = "[u8]")]
+        payload: Vec<u8>,
+        code: u8,
+    },
+}
+
+fn main() {
+    let mut data = Packet::Data { payload: vec![1, 2, 3], route: "/sensors".into() };
+    let control = Packet::Control { payload: Vec::new(), code: 7 };
+
+    let payload: &[u8] = data.payload();
+    assert_eq!(payload, [1, 2, 3]);
+    assert!(control.payload().is_empty());
+
+    data.payload_mut().reverse();
+    assert_eq!(data.payload(), [3, 2, 1]);
+
+    let route: Option<&str> = data.route();
+    assert_eq!(route, Some("/sensors"));
+    assert_eq!(control.route(), None);
+    assert_eq!(control.code(), Some(&7));
+}
@@ -0,0 +1,56 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Message`] of which the fields
+//! spell the same type differently between variants.
+//!
+//! The paths of the types of the prelude are ignored when comparing the types
+//! of a field, so `payload` and `sender` are present in every variant and
+//! their getters don't return an `Option`.
+
+extern crate alloc;
+
+/// A message of a chat protocol.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Message {
+    Text {
+        payload: Vec<u8>,
+        sender: Option<String>,
+    },
+
+    Binary {
+        payload: std::vec::Vec<u8>,
+        sender: ::core::option::Option<std::string::String>,
+    },
+
+    Compressed {
+        payload: alloc::vec::Vec<u8>,
+        sender: Option<::std::string::String>,
+    },
+}
+
+fn main() {
+    let text = Message::Text {
+        payload: b"Hello".to_vec(),
+        sender: Some("Alice".into()),
+    };
+
+    let binary = Message::Binary {
+        payload: vec![0xFF, 0xD8],
+        sender: None,
+    };
+
+    let compressed = Message::Compressed {
+        payload: vec![0x1F, 0x8B],
+        sender: Some("Bob".into()),
+    };
+
+    let payload: &Vec<u8> = text.payload();
+    assert_eq!(payload, b"Hello");
+    assert_eq!(binary.payload(), &[0xFF, 0xD8]);
+    assert_eq!(compressed.payload().len(), 2);
+
+    let sender: &Option<String> = binary.sender();
+    assert_eq!(sender, &None);
+    assert_eq!(text.sender().as_deref(), Some("Alice"));
+}
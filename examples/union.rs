@@ -0,0 +1,56 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases a union [`Bits`], which is annotated with
+//! `#[enum_fields(unsafe_getters)]`.
+//!
+//! A union doesn't know which of its fields holds a value, so reading one is
+//! `unsafe`. The getters `Bits::integer()` and `Bits::float()` are `unsafe` as
+//! well, and the caller must make sure the field holds a valid value.
+//!
+//! The union [`Word`] changes the names of its getters using
+//! `#[enum_fields(getter_prefix = "as_", mut_suffix = "_bits")]`, so its
+//! getters are `Word::as_signed()` and `Word::as_signed_bits()`.
+
+/// The bits of a 32-bit value, interpreted as either an integer or a float.
+#[derive(Clone, Copy, enum_fields::EnumFields)]
+#[enum_fields(unsafe_getters)]
+pub union Bits {
+    integer: u32,
+    float: f32,
+
+    #[enum_fields(readonly)]
+    bytes: [u8; 4],
+}
+
+/// A 16-bit word, interpreted as either signed or unsigned.
+#[derive(Clone, Copy, enum_fields::EnumFields)]
+#[enum_fields(unsafe_getters, getter_prefix = "as_", mut_suffix = "_bits")]
+pub union Word {
+    signed: i16,
+    unsigned: u16,
+}
+
+fn main() {
+    let mut bits = Bits { float: 1.0 };
+
+    // SAFETY: Every bit pattern is a valid `u32`, `f32` and `[u8; 4]`.
+    unsafe {
+        assert_eq!(bits.float(), &1.0);
+        assert_eq!(bits.integer(), &0x3F80_0000);
+
+        *bits.integer_mut() += 1;
+        assert_eq!(bits.float(), &f32::from_bits(0x3F80_0001));
+        assert_eq!(bits.bytes(), &0x3F80_0001_u32.to_ne_bytes());
+    }
+
+    let mut word = Word { unsigned: u16::MAX };
+
+    // SAFETY: Every bit pattern is a valid `i16` and `u16`.
+    unsafe {
+        assert_eq!(word.as_signed(), &-1);
+
+        *word.as_signed_bits() = i16::MIN;
+        assert_eq!(word.as_unsigned(), &0x8000);
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Packet`] of which the fields are
+//! dynamically sized types behind a reference or a pointer.
+//!
+//! The getters construct their return values explicitly, without relying on
+//! type inference, so `Packet::payload()` returns the `&'a [u8]` itself, and
+//! `Packet::buffer()` an `Option<&&'a mut [u8]>`.
+
+/// A packet, borrowing its payload from a buffer.
+#[derive(Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Packet<'a> {
+    Data {
+        payload: &'a [u8],
+        #[enum_fields(deref)]
+        label: Box<str>,
+    },
+
+    Scratch {
+        payload: &'a [u8],
+        buffer: &'a mut [u8],
+    },
+}
+
+fn main() {
+    let bytes = [1, 2, 3, 4];
+    let mut scratch = [0; 2];
+
+    let data = Packet::Data { payload: &bytes[..2], label: "head".into() };
+    let mut packet = Packet::Scratch { payload: &bytes[2..], buffer: &mut scratch };
+
+    let payload: &[u8] = data.payload();
+    assert_eq!(payload, [1, 2]);
+    assert_eq!(data.label(), Some("head"));
+    assert_eq!(data.buffer(), None);
+
+    if let Some(buffer) = packet.buffer_mut() {
+        buffer.copy_from_slice(&[5, 6]);
+    }
+    assert_eq!(packet.payload(), [3, 4]);
+    assert_eq!(packet.buffer().map(|buffer| &buffer[..]), Some(&[5, 6][..]));
+    assert_eq!(packet.label(), None);
+}
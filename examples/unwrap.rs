@@ -0,0 +1,62 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`] annotated with
+//! `#[enum_fields(unwrap)]`.
+//!
+//! Only `Company` has a `ceo`, so `Entity::unwrap_ceo()` is generated, which
+//! returns a `&String` and panics for a `Person`. Since every variant has a
+//! `name`, no `Entity::unwrap_name()` is generated. The getter is
+//! `#[track_caller]`, so the location of the panic is the call of
+//! `Entity::unwrap_ceo()` in this file.
+
+use std::panic::{self, Location};
+use std::sync::Mutex;
+
+/// The location of the last panic, as reported to the panic hook.
+static PANIC_LOCATION: Mutex<Option<(String, u32)>> = Mutex::new(None);
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(unwrap)]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+fn main() {
+    let company = Entity::Company {
+        name: "Apple".into(),
+        ceo: "Tim Cook".into(),
+    };
+
+    let person = Entity::Person {
+        name: "Tim Berners-Lee".into(),
+    };
+
+    assert_eq!(company.unwrap_ceo(), "Tim Cook");
+
+    panic::set_hook(Box::new(|info| {
+        *PANIC_LOCATION.lock().unwrap() = info.location()
+            .map(|location| (location.file().to_string(), location.line()));
+    }));
+
+    let call_site = Location::caller();
+    let panic = panic::catch_unwind(|| person.unwrap_ceo().len()).unwrap_err();
+    drop(panic::take_hook());
+
+    assert_eq!(
+        *PANIC_LOCATION.lock().unwrap(),
+        Some((call_site.file().to_string(), call_site.line() + 1)),
+    );
+    assert_eq!(
+        panic.downcast_ref::<&str>().copied(),
+        Some("called `unwrap_ceo()` on the `Person` variant, which doesn't have the field `ceo`"),
+    );
+}
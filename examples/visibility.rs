@@ -0,0 +1,77 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`accounts::Account`], of which the
+//! generated methods are only visible within the crate, using
+//! `#[enum_fields(vis = "pub(crate)")]`.
+//!
+//! The `password` field overrides this visibility with `#[enum_fields(vis = "")]`,
+//! which makes its getters private to the `accounts` module.
+//!
+//! The enum [`accounts::Session`] is annotated with
+//! `#[enum_fields(inherit_vis)]`, so its methods are `pub(crate)` as well,
+//! like the enum itself.
+
+mod accounts {
+    /// An account that can be either a user or a service.
+    #[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+    #[enum_fields(vis = "pub(crate)")]
+    pub enum Account {
+        User {
+            name: String,
+
+            #[enum_fields(vis = "")]
+            password: String,
+        },
+
+        Service {
+            name: String,
+        },
+    }
+
+    /// A session of an account, which is only used within the crate.
+    #[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+    #[enum_fields(inherit_vis)]
+    pub(crate) enum Session {
+        Active {
+            token: String,
+        },
+
+        Expired,
+    }
+
+    impl Account {
+        /// Check the password of the account, which uses the private getter.
+        pub fn verify(&self, password: &str) -> bool {
+            self.password().is_some_and(|expected| expected == password)
+        }
+    }
+}
+
+use accounts::{Account, Session};
+
+fn main() {
+    let mut user = Account::User {
+        name: "admin".into(),
+        password: "hunter2".into(),
+    };
+
+    let service = Account::Service {
+        name: "backup".into(),
+    };
+
+    assert_eq!(user.name(), "admin");
+    assert_eq!(service.name(), "backup");
+    assert!(user.is_user());
+
+    user.name_mut().push_str("istrator");
+    assert_eq!(user.name(), "administrator");
+
+    // `Account::password()` is private to the `accounts` module.
+    assert!(user.verify("hunter2"));
+    assert!(!service.verify("hunter2"));
+
+    let session = Session::Active { token: "abc".into() };
+    assert_eq!(session.token(), Some(&"abc".into()));
+    assert!(Session::Expired.is_expired());
+}
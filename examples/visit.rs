@@ -0,0 +1,46 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`] annotated with
+//! `#[enum_fields(visit)]`.
+//!
+//! For every field, a method is generated that calls a function with the
+//! field, e.g. `Entity::for_each_name()` calling it for every entity, and
+//! `Entity::for_each_ceo()` only calling it for a `Company`, since a `Person`
+//! doesn't have a `ceo`.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(visit)]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+fn main() {
+    let entities = [
+        Entity::Company {
+            name: "Apple".into(),
+            ceo: "Tim Cook".into(),
+        },
+        Entity::Person {
+            name: "Tim Berners-Lee".into(),
+        },
+    ];
+
+    let mut name_lengths = 0;
+    let mut ceos = Vec::new();
+    for entity in &entities {
+        entity.for_each_name(|name| name_lengths += name.len());
+        entity.for_each_ceo(|ceo| ceos.push(ceo.clone()));
+    }
+
+    assert_eq!(name_lengths, 20);
+    assert_eq!(ceos, ["Tim Cook"]);
+}
@@ -0,0 +1,182 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases a wide enum [`Message`], with many
+//! variants sharing the same fields, as found in generated protocol code.
+//!
+//! The `id` and `name` fields are present in every variant, while `even` and
+//! `third` are only present in some of them. The getters of every field share
+//! the same patterns for all of these variants.
+
+/// A message of a protocol.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(setters)]
+pub enum Message {
+    Variant0 {
+        id: u32,
+        name: String,
+        even: bool,
+        third: (u8, u8),
+    },
+
+    Variant1 {
+        id: u32,
+        name: String,
+    },
+
+    Variant2 {
+        id: u32,
+        name: String,
+        even: bool,
+    },
+
+    Variant3 {
+        id: u32,
+        name: String,
+        third: (u8, u8),
+    },
+
+    Variant4 {
+        id: u32,
+        name: String,
+        even: bool,
+    },
+
+    Variant5 {
+        id: u32,
+        name: String,
+    },
+
+    Variant6 {
+        id: u32,
+        name: String,
+        even: bool,
+        third: (u8, u8),
+    },
+
+    Variant7 {
+        id: u32,
+        name: String,
+    },
+
+    Variant8 {
+        id: u32,
+        name: String,
+        even: bool,
+    },
+
+    Variant9 {
+        id: u32,
+        name: String,
+        third: (u8, u8),
+    },
+
+    Variant10 {
+        id: u32,
+        name: String,
+        even: bool,
+    },
+
+    Variant11 {
+        id: u32,
+        name: String,
+    },
+
+    Variant12 {
+        id: u32,
+        name: String,
+        even: bool,
+        third: (u8, u8),
+    },
+
+    Variant13 {
+        id: u32,
+        name: String,
+    },
+
+    Variant14 {
+        id: u32,
+        name: String,
+        even: bool,
+    },
+
+    Variant15 {
+        id: u32,
+        name: String,
+        third: (u8, u8),
+    },
+
+    Variant16 {
+        id: u32,
+        name: String,
+        even: bool,
+    },
+
+    Variant17 {
+        id: u32,
+        name: String,
+    },
+
+    Variant18 {
+        id: u32,
+        name: String,
+        even: bool,
+        third: (u8, u8),
+    },
+
+    Variant19 {
+        id: u32,
+        name: String,
+    },
+
+    Variant20 {
+        id: u32,
+        name: String,
+        even: bool,
+    },
+
+    Variant21 {
+        id: u32,
+        name: String,
+        third: (u8, u8),
+    },
+
+    Variant22 {
+        id: u32,
+        name: String,
+        even: bool,
+    },
+
+    Variant23 {
+        id: u32,
+        name: String,
+    },
+}
+
+fn main() {
+    let mut messages = vec![
+        Message::Variant0 { id: 0, name: "zero".into(), even: true, third: (0, 0) },
+        Message::Variant1 { id: 1, name: "one".into() },
+        Message::Variant2 { id: 2, name: "two".into(), even: true },
+        Message::Variant3 { id: 3, name: "three".into(), third: (1, 3) },
+        Message::Variant23 { id: 23, name: "twenty-three".into() },
+    ];
+
+    let ids: Vec<u32> = messages.iter().map(|message| *message.id()).collect();
+    assert_eq!(ids, [0, 1, 2, 3, 23]);
+
+    let even: Vec<Option<&bool>> = messages.iter().map(Message::even).collect();
+    assert_eq!(even, [Some(&true), None, Some(&true), None, None]);
+
+    let thirds = messages.iter().filter_map(Message::third).count();
+    assert_eq!(thirds, 2);
+
+    for message in &mut messages {
+        message.name_mut().make_ascii_uppercase();
+        message.set_even(false);
+    }
+
+    assert_eq!(messages[3].name(), "THREE");
+    assert_eq!(messages[2].even(), Some(&false));
+    assert!(messages[4].is_variant23());
+}
@@ -0,0 +1,45 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases an enum [`Entity`] annotated with
+//! `#[enum_fields(with)]`.
+//!
+//! For every field, a builder-style method is generated that sets the field
+//! and returns the entity, e.g. `Entity::with_name()`. Since a `Person`
+//! doesn't have a `ceo`, `Entity::with_ceo()` returns it unchanged.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+#[enum_fields(with)]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+fn main() {
+    let company = Entity::Company {
+        name: "Apple".into(),
+        ceo: "Tim Cook".into(),
+    };
+
+    let company = company
+        .with_name("Microsoft".into())
+        .with_ceo("Satya Nadella".into());
+
+    assert_eq!(company, Entity::Company {
+        name: "Microsoft".into(),
+        ceo: "Satya Nadella".into(),
+    });
+
+    let person = Entity::Person { name: "Tim".into() }
+        .with_ceo("Nobody".into())
+        .with_name("Tim Berners-Lee".into());
+
+    assert_eq!(person, Entity::Person { name: "Tim Berners-Lee".into() });
+}
@@ -0,0 +1,46 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The following example showcases a wrapper [`Owner`] around an enum
+//! [`Entity`], which is annotated with `#[enum_fields_delegate(Entity, 0)]`.
+//!
+//! The getters of [`Entity`], such as `Entity::name()` and `Entity::ceo()`,
+//! are generated on [`Owner`] as well, forwarding to the wrapped enum. The
+//! wrapper must be declared after the enum, in the same module.
+
+/// An entity that can be either a `Company` or a `Person`.
+#[derive(Clone, Debug, PartialEq, Eq, enum_fields::EnumFields)]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+/// The owner of a property, which is an entity.
+#[enum_fields::enum_fields_delegate(Entity, 0)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Owner(Entity);
+
+fn main() {
+    let mut company = Owner(Entity::Company {
+        name: "Apple".into(),
+        ceo: "Tim Cook".into(),
+    });
+
+    let person = Owner(Entity::Person {
+        name: "Tim Berners-Lee".into(),
+    });
+
+    assert_eq!(company.name(), "Apple");
+    assert_eq!(company.ceo(), Some(&"Tim Cook".into()));
+    assert_eq!(person.name(), "Tim Berners-Lee");
+    assert_eq!(person.ceo(), None);
+
+    *company.name_mut() = "Microsoft".into();
+    assert_eq!(company.0.name(), "Microsoft");
+}
@@ -0,0 +1,23 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::fmt::{Display, Formatter};
+
+/// The error returned by the `try_<field>()` getters, when the active variant
+/// doesn't have the requested field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EnumFieldError {
+    /// The name of the field that was requested.
+    pub field: &'static str,
+
+    /// The name of the active variant, which doesn't have the field.
+    pub variant: &'static str,
+}
+
+impl Display for EnumFieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "variant `{}` doesn't have the field `{}`", self.variant, self.field)
+    }
+}
+
+impl std::error::Error for EnumFieldError {}
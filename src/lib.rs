@@ -75,156 +75,1239 @@
 //! *company.name_mut() = "Microsoft".into();
 //! assert_eq!(company.name(), "Microsoft");
 //! ```
+//!
+//! ### Tuple Variants
+//! Fields of tuple variants don't have a name, so they are exposed through
+//! positional getters instead: `field_0()`, `field_1()`, and so on. These are
+//! keyed on both the position and the type of the field, meaning a variant
+//! only participates when the field at that position has the same type as the
+//! first variant that has one there.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Address {
+//!     V4(u8, u8, u8, u8),
+//!     Named(String),
+//! }
+//!
+//! let address = Address::V4(127, 0, 0, 1);
+//! assert_eq!(address.field_0(), Some(&127));
+//! assert_eq!(address.field_3(), Some(&1));
+//! ```
+//!
+//! ### Variant Predicates
+//! For every variant, a predicate is generated to check whether the enum is
+//! that variant, named after the variant in `snake_case`:
+//!
+//! ```rs
+//! assert!(company.is_company());
+//! assert!(!person.is_company());
+//! assert!(person.is_person());
+//! ```
+//!
+//! The predicates and the other helpers of the variants are generated for
+//! enums without any fields too, such as C-like enums, so deriving
+//! [`EnumFields`] is still useful for them:
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[repr(u8)]
+//! pub enum Color {
+//!     Red = 1,
+//!     Green = 2,
+//!     Blue = 4,
+//! }
+//!
+//! assert!(Color::Red.is_red());
+//! assert_eq!(Color::Green.variant_name(), "Green");
+//! ```
+//!
+//! ### Field Types
+//! Named fields that are shared between variants must have the same type in
+//! every variant, since the getter can only return a single type. Using a
+//! different type for a field with the same name results in a compile error
+//! pointing at the offending field.
+//!
+//! The types are compared by their spelling, as the derive macro can't resolve
+//! them. The paths of `Box`, `Option`, `Result`, `String` and `Vec` are
+//! ignored, so `Vec<u8>` and `std::vec::Vec<u8>` are the same type. Other
+//! spellings of the same type, such as a type alias, are considered a
+//! different type, and the field has to be spelled the same in every variant.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Message {
+//!     Text { payload: Vec<u8> },
+//!     Binary { payload: std::vec::Vec<u8> },
+//! }
+//! ```
+//!
+//! ### References
+//! Fields of which the type is a shared reference, such as `&'a str`, are
+//! returned by value, since a reference can be copied out of the variant. The
+//! getter therefore returns a `&'a str` with the lifetime of the field, instead
+//! of a `&&'a str` borrowing the enum.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Token<'a> {
+//!     Word { text: &'a str },
+//!     Number { text: &'a str, value: i64 },
+//! }
+//!
+//! let text: &'a str = token.text();
+//! ```
+//!
+//! ### Keywords
+//! Fields named after a keyword using a raw identifier, such as `r#type`, get
+//! a raw getter as well: `r#type()`. The mutable getter isn't a keyword, so it
+//! is simply named `type_mut()`.
+//!
+//! ### Deprecated Fields
+//! Fields marked `#[deprecated]` forward that attribute to their getters,
+//! setters and other generated methods, so callers of those methods get the
+//! deprecation warning as well. When the field is deprecated in multiple
+//! variants, the attribute of the first one is used.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Entity {
+//!     Company {
+//!         #[deprecated = "use `display_name` instead"]
+//!         name: String,
+//!         display_name: String,
+//!     },
+//! }
+//!
+//! // warning: use of deprecated method `Entity::name`: use `display_name` instead
+//! let name = company.name();
+//! ```
+//!
+//! ### Conditional Variants
+//! The `#[cfg(...)]` attributes of variants are forwarded to the match arms and
+//! the methods generated for those variants, such as their predicates, so they
+//! are configured out along with the variant.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Backend {
+//!     Memory { capacity: usize },
+//!     #[cfg(feature = "mock")]
+//!     Mock { capacity: usize },
+//! }
+//! ```
+//!
+//! Fields are configured before the derive sees them, so a field annotated with
+//! `#[cfg(...)]` only gets getters when it is compiled, and `#[enum_fields(...)]`
+//! attributes can be applied conditionally using `#[cfg_attr(...)]`. Any other
+//! attribute on a field, such as `#[rustfmt::skip]`, is left to its owner.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Setting {
+//!     Range {
+//!         #[cfg_attr(feature = "legacy", enum_fields(rename = "current"))]
+//!         value: i64,
+//!         #[cfg(feature = "limits")]
+//!         maximum: i64,
+//!     },
+//! }
+//! ```
+//!
+//! ### Non-Exhaustive Enums
+//! For enums marked `#[non_exhaustive]`, the getters of fields that aren't
+//! present in every variant get a trailing wildcard arm returning `None`, so
+//! the generated code keeps working when variants are added. Getters that
+//! return the field directly still require every variant to have the field.
+//!
+//! ### Variant Names
+//! The name of the active variant can be retrieved using `variant_name()`,
+//! which is useful for logging. A field named `variant_name` clashes with this
+//! method, and must therefore be renamed.
+//!
+//! ```rs
+//! assert_eq!(company.variant_name(), "Company");
+//! assert_eq!(person.variant_name(), "Person");
+//! ```
+//!
+//! The names of all variants are available in the associated constant
+//! `VARIANTS`, in declaration order. A field named `VARIANTS` clashes with it.
+//!
+//! ```rs
+//! assert_eq!(Entity::VARIANTS, ["Company", "Person"]);
+//! ```
+//!
+//! ### Field Names
+//! The names of the fields of the active variant can be retrieved using
+//! `field_names()`, in declaration order. Tuple and unit variants don't have
+//! named fields, so they return an empty slice.
+//!
+//! ```rs
+//! assert_eq!(company.field_names(), ["name", "ceo"]);
+//! assert_eq!(person.field_names(), ["name"]);
+//! ```
+//!
+//! Similarly, `field_count()` returns the number of fields of the active
+//! variant, which does include the fields of tuple variants.
+//!
+//! ```rs
+//! assert_eq!(company.field_count(), 2);
+//! ```
+//!
+//! ### Local Enums
+//! Enums can derive [`EnumFields`] wherever they are declared, including inside
+//! a function body or the body of a method in an `impl` block. The generated
+//! methods can be called the same as for an enum declared in a module.
+//!
+//! ### Explicit Discriminants
+//! Variants with an explicit discriminant, such as `Push { value: u8 } = 0x10`
+//! in a `#[repr(u8)]` enum, get the same methods as other variants, since the
+//! discriminant isn't part of the patterns matching the variant.
+//!
+//! ### Empty Enums
+//! Enums without any variants can't be constructed, so they don't get any
+//! methods. The derive still generates an empty `impl` block for them, along
+//! with an empty companion trait if one is requested, so they can be used with
+//! code that is generic over enums deriving [`EnumFields`].
+//!
+//! ## Attributes
+//! The generated getters can be customized using the `#[enum_fields(...)]`
+//! attribute. Any other attribute, such as `#[serde(...)]`, is left intact for
+//! the other derives of the enum.
+//!
+//! ### Always Optional
+//! Annotating the enum with `#[enum_fields(always_optional)]` makes every
+//! getter return an `Option`, even for fields that are present in every
+//! variant. This keeps the signatures of the getters stable when a variant
+//! without that field is added later on. Options requiring a field that is
+//! present in every variant, like `eq_field`, `shared_key` or `transparent`,
+//! still work with such fields.
+//!
+//! ```rs
+//! assert_eq!(company.name(), Some(&"Apple".into()));
+//! ```
+//!
+//! ### Unwrapping Getters
+//! Annotating the enum with `#[enum_fields(unwrap)]` generates getters that
+//! return the field directly for fields that aren't present in every variant,
+//! e.g. `unwrap_ceo()`. Like [`Option::unwrap`], these panic if the active
+//! variant doesn't have the field, with a message naming that variant. They
+//! are `#[track_caller]`, so the panic points at the call of the getter.
+//!
+//! ```rs
+//! assert_eq!(company.unwrap_ceo(), "Tim Cook");
+//!
+//! // panics: called `unwrap_ceo()` on the `Person` variant, which doesn't have the field `ceo`
+//! person.unwrap_ceo();
+//! ```
+//!
+//! ### Getters With a Default
+//! Annotating the enum with `#[enum_fields(or)]` generates getters returning a
+//! reference to either the field or a default passed by the caller, for fields
+//! that aren't present in every variant, e.g. `ceo_or(&default)`. Like
+//! [`Option::unwrap_or`], this avoids handling the `Option` at every call.
+//!
+//! ```rs
+//! let nobody = String::from("nobody");
+//! assert_eq!(company.ceo_or(&nobody), "Tim Cook");
+//! assert_eq!(person.ceo_or(&nobody), "nobody");
+//! ```
+//!
+//! ### Consuming Getters
+//! Annotating the enum with `#[enum_fields(into)]` generates getters that
+//! consume the enum and move the field out of it, e.g. `into_name()`
+//! returning a `String` and `into_ceo()` returning an `Option<String>`.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(into)]
+//! pub enum Entity {
+//!     ...
+//! }
+//!
+//! let name: String = company.into_name();
+//! ```
+//!
+//! The fields of an enum are always `Sized`, as an unsized type such as `str`
+//! or a `T: ?Sized` can only be stored behind a pointer like `Box<T>`, which
+//! is moved out instead. However, a field can't be moved out of an enum that
+//! implements `Drop`, so the consuming getters don't compile for such enums.
+//!
+//! ### Extracting Single-Field Variants
+//! Annotating a field with `#[enum_fields(from)]` generates a consuming
+//! `into_<field>_opt()` method, which returns the field for the variants that
+//! hold nothing but that field, and `None` for all other variants, as their
+//! other fields would be lost otherwise.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Entity {
+//!     Company {
+//!         name: String,
+//!         ceo: String,
+//!     },
+//!
+//!     Person {
+//!         #[enum_fields(from)]
+//!         name: String,
+//!     },
+//! }
+//!
+//! assert_eq!(person.into_name_opt(), Some("Tim Berners-Lee".into()));
+//! assert_eq!(company.into_name_opt(), None);
+//! ```
+//!
+//! No `From<Entity>` implementations are generated instead, since an
+//! `impl From<Entity> for Option<String>` would conflict with the one of any
+//! other field of the same type, and isn't even allowed for foreign types.
+//!
+//! ### Mapping Fields
+//! Annotating the enum with `#[enum_fields(map)]` generates methods applying a
+//! function to a reference to the field, e.g. `map_name(f)`. Like
+//! [`Option::map`], this returns `None` for variants without the field.
+//!
+//! ```rs
+//! assert_eq!(company.map_name(String::len), 5);
+//! assert_eq!(person.map_ceo(|ceo| ceo.to_uppercase()), None);
+//! ```
+//!
+//! ### Visiting Fields
+//! Annotating the enum with `#[enum_fields(visit)]` generates methods calling a
+//! function with a reference to the field, e.g. `for_each_name(f)`. The
+//! function isn't called for variants without the field, so nothing has to be
+//! returned or allocated for them.
+//!
+//! ```rs
+//! let mut names = Vec::new();
+//! company.for_each_ceo(|ceo| names.push(ceo.clone()));
+//! person.for_each_ceo(|ceo| names.push(ceo.clone()));
+//! assert_eq!(names, ["Tim Cook"]);
+//! ```
+//!
+//! ### Comparing Fields
+//! Annotating the enum with `#[enum_fields(compare)]` generates methods
+//! comparing a field of two enums, e.g. `same_name(&other)`. For fields that
+//! aren't present in every variant, this returns `false` if either enum
+//! doesn't have the field. These methods require the type of the field to be
+//! `PartialEq`: fields of other types still get the method, but it can't be
+//! called.
+//!
+//! ```rs
+//! assert!(company.same_name(&Entity::Person { name: "Apple".into() }));
+//! assert!(!company.same_ceo(&person));
+//! ```
+//!
+//! To compare whole enums by a single field, such as an identifier, the enum
+//! can be annotated with `#[enum_fields(eq_field = "...")]` naming a field that
+//! is present in every variant. This generates a method like `eq_by_id(&other)`
+//! instead of a `PartialEq` implementation, so it doesn't conflict with one.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(eq_field = "id")]
+//! pub enum Entity {
+//!     Company { id: u64, name: String },
+//!     Person { id: u64, name: String },
+//! }
+//!
+//! assert!(company.eq_by_id(&renamed_company));
+//! ```
+//!
+//! ### String Slices
+//! Annotating the enum with `#[enum_fields(str_accessors)]` generates an
+//! additional getter for every `String` field, returning a `&str` (or an
+//! `Option<&str>`) instead: `name_str()`.
+//!
+//! ```rs
+//! assert_eq!(company.name_str(), "Apple");
+//! assert_eq!(person.ceo_str(), None);
+//! ```
+//!
+//! ### Variant Getters
+//! Annotating the enum with `#[enum_fields(as_variant)]` generates a getter per
+//! variant, returning all of its fields in declaration order when the enum is
+//! that variant, e.g. `as_company()` returning an
+//! `Option<(&String, &String)>`. Variants with a single field return that
+//! field directly, and unit variants return an `Option<()>`. The mutable
+//! counterpart is `as_company_mut()`.
+//!
+//! ```rs
+//! if let Some((name, ceo)) = company.as_company() {
+//!     println!("{name} is led by {ceo}");
+//! }
+//!
+//! assert_eq!(person.as_person(), Some(&"Tim Berners-Lee".into()));
+//! ```
+//!
+//! ### Path Slices
+//! Similarly, annotating the enum with `#[enum_fields(path_accessors)]`
+//! generates an additional getter for every `PathBuf` field, returning a
+//! `&Path` (or an `Option<&Path>`) instead: `config_path()`.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(path_accessors)]
+//! pub enum Command {
+//!     Build { config: PathBuf },
+//!     Clean,
+//! }
+//!
+//! assert_eq!(command.config_path(), Some(Path::new("build.toml")));
+//! ```
+//!
+//! ### Iterators
+//! Annotating the enum with `#[enum_fields(iter)]` generates an additional
+//! getter for every `Vec<T>` field, returning an iterator over its elements
+//! (or an `Option` of one): `tags_iter()`.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(iter)]
+//! pub enum Post {
+//!     Article { tags: Vec<String> },
+//!     Draft { tags: Vec<String> },
+//! }
+//!
+//! let tags: Vec<&String> = post.tags_iter().collect();
+//! ```
+//!
+//! ### Setters
+//! Annotating the enum with `#[enum_fields(setters)]` generates setters, e.g.
+//! `set_name(value)`. For fields that aren't present in every variant, the
+//! setter returns whether the active variant had the field; if it didn't,
+//! nothing is changed.
+//!
+//! ```rs
+//! company.set_name("Microsoft".into());
+//! assert!(company.set_ceo("Satya Nadella".into()));
+//! assert!(!person.set_ceo("Nobody".into()));
+//! ```
+//!
+//! ### Replacing Fields
+//! Annotating the enum with `#[enum_fields(replace)]` generates
+//! `replace_<field>(value)` methods, which store the new value using
+//! [`std::mem::replace`] and return the previous one. For fields that aren't
+//! present in every variant, an `Option` is returned instead. When the active
+//! variant doesn't have the field, `None` is returned and the new value is
+//! dropped without being stored.
+//!
+//! ```rs
+//! assert_eq!(company.replace_name("Alphabet".into()), "Google");
+//! assert_eq!(company.replace_ceo("Sundar Pichai".into()), Some("Larry Page".into()));
+//! assert_eq!(person.replace_ceo("Nobody".into()), None);
+//! ```
+//!
+//! ### Swapping Fields
+//! Annotating the enum with `#[enum_fields(swap)]` generates
+//! `swap_<field>(other)` methods, which swap the field with the one of another
+//! enum using [`std::mem::swap`]. For fields that aren't present in every
+//! variant, the fields are only swapped when both enums have the field, which
+//! is returned as a `bool` like the setters do.
+//!
+//! ```rs
+//! company.swap_name(&mut person);
+//! assert_eq!(company.name(), "Tim Berners-Lee");
+//! assert!(!company.swap_ceo(&mut person));
+//! ```
+//!
+//! ### Taking Fields
+//! Annotating the enum with `#[enum_fields(take)]` generates
+//! `take_<field>()` methods for fields whose type implements `Default`. They
+//! move the value out of the enum using [`std::mem::take`], leaving the
+//! default in its place. For fields that aren't present in every variant, an
+//! `Option` is returned, which is `None` for the variants without the field.
+//! Fields of which the type doesn't implement `Default` still get the method,
+//! but it can't be called, so they don't have to be excluded.
+//!
+//! ```rs
+//! assert_eq!(company.take_ceo(), Some("Satya Nadella".into()));
+//! assert_eq!(company.ceo(), Some(&String::new()));
+//! assert_eq!(person.take_ceo(), None);
+//! ```
+//!
+//! ### Read-Only Fields
+//! Annotating the enum with `#[enum_fields(readonly)]` suppresses the mutable
+//! getters, e.g. `name_mut()`, as well as the setters and builder methods of
+//! all fields, leaving only the immutable getters. The same attribute can be
+//! used on a field to make only that field read-only. Since the getters are
+//! shared between the variants, this applies to that field in every variant.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Entity {
+//!     Company {
+//!         #[enum_fields(readonly)]
+//!         id: u64,
+//!         name: String,
+//!     },
+//!     Person {
+//!         id: u64,
+//!         name: String,
+//!     },
+//! }
+//!
+//! *company.name_mut() = "Microsoft".into();
+//! // There is no `company.id_mut()`.
+//! ```
+//!
+//! To only suppress the mutable getter of a field, while keeping its setters,
+//! annotate the field with `#[enum_fields(no_mut)]` instead. This is useful for
+//! fields of types like `Rc<T>`, for which a mutable reference is rarely
+//! useful. Unlike `readonly`, `no_mut` only applies to the variants in which
+//! the field is annotated with it. If only some of the variants have it, the
+//! mutable getter is still generated, but it returns `None` for those
+//! variants, so it returns an `Option<&mut T>` even if the field is present in
+//! every variant.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Record {
+//!     Book {
+//!         notes: String,
+//!     },
+//!     Film {
+//!         #[enum_fields(no_mut)]
+//!         notes: String,
+//!     },
+//! }
+//!
+//! assert_eq!(book.notes_mut(), Some(&mut String::new()));
+//! assert_eq!(film.notes_mut(), None);
+//! ```
+//!
+//! ### Builder Methods
+//! Annotating the enum with `#[enum_fields(with)]` generates builder-style
+//! methods taking the enum by value, e.g. `with_name(value)`, which set the
+//! field like a setter and return the enum. Variants without the field are
+//! returned unchanged.
+//!
+//! ```rs
+//! let company = company
+//!     .with_name("Microsoft".into())
+//!     .with_ceo("Satya Nadella".into());
+//! ```
+//!
+//! ### Skipping Fields
+//! Fields annotated with `#[enum_fields(skip)]` don't get any getters. When a
+//! field is skipped on one variant but not on the others, that variant is
+//! treated as not having the field, so the getter becomes optional.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Node {
+//!     Element {
+//!         name: String,
+//!     },
+//!     Cached {
+//!         name: String,
+//!         #[enum_fields(skip)]
+//!         cache: RefCell<Vec<u8>>,
+//!     },
+//! }
+//! ```
+//!
+//! Conversely, annotating the enum with `#[enum_fields(only(...))]` restricts
+//! the generated methods to the listed fields, which is more convenient when
+//! only a few fields of a wide enum are of interest. Every listed field must be
+//! present in at least one variant.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(only(id, name))]
+//! pub enum Message {
+//!     ...
+//! }
+//! ```
+//!
+//! ### Ignoring Variants
+//! Variants annotated with `#[enum_fields(ignore)]` don't take part in sharing
+//! fields, and are treated as not having any. This makes it possible to keep a
+//! sentinel variant, such as one for unparsable input, of which the fields
+//! don't have to match the types of the other variants.
+//!
+//! Fields that every other variant has are returned directly, like fields
+//! present in every variant. Such fields need a `default` or `or_default`,
+//! which the getter returns for the ignored variants. Getters of the other
+//! fields return `None` for them.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Message {
+//!     Chat {
+//!         #[enum_fields(default = "0")]
+//!         id: u32,
+//!         text: String,
+//!     },
+//!     Leave { id: u32 },
+//!     #[enum_fields(ignore)]
+//!     Unknown(String),
+//! }
+//!
+//! assert_eq!(message.id(), &1);
+//! assert_eq!(unknown.id(), &0);
+//! assert_eq!(unknown.text(), None);
+//! ```
+//!
+//! ### Renaming Getters
+//! The getters of a field can be renamed using
+//! `#[enum_fields(rename = "...")]`, which is useful when the name of the field
+//! clashes with another method. The rename applies to that field of that
+//! variant only, so fields that should share a getter must all be renamed.
+//! Keywords have to be written in their raw form, e.g. `rename = "r#type"`.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Entity {
+//!     Company {
+//!         #[enum_fields(rename = "display_name")]
+//!         name: String,
+//!     },
+//!     Person {
+//!         #[enum_fields(rename = "display_name")]
+//!         name: String,
+//!     },
+//! }
+//!
+//! assert_eq!(company.display_name(), "Apple");
+//! *company.display_name_mut() = "Microsoft".into();
+//! ```
+//!
+//! Fields named after the methods of commonly derived traits, i.e. `clone`,
+//! `eq`, `cmp`, `fmt`, `hash`, `partial_cmp` and `default`, must be renamed, as
+//! their getters would shadow those methods.
+//!
+//! ### Getter Documentation
+//! The generated getters have a generic documentation. A field can specify the
+//! documentation of its getter and mutable getter instead using
+//! `#[enum_fields(getter_doc = "...")]`.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Entity {
+//!     Company {
+//!         #[enum_fields(getter_doc = "The registered name of the company or person.")]
+//!         name: String,
+//!     },
+//!     ...
+//! }
+//! ```
+//!
+//! ### Variant Case
+//! The names of the methods generated for variants, like `is_company_name()`
+//! for a `CompanyName` variant, contain the name of the variant in
+//! `snake_case`. Using `#[enum_fields(case = "...")]` on the enum, the names
+//! can be in `lowerCamelCase` (`"camel"`) or kept as they are (`"verbatim"`)
+//! instead, e.g. `is_companyName()` or `is_CompanyName()`. The default is
+//! `"snake"`.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(case = "verbatim")]
+//! pub enum Status {
+//!     Ok,
+//!     HTTPError { code: u16 },
+//! }
+//!
+//! assert!(status.is_HTTPError());
+//! ```
+//!
+//! ### Getter Prefix
+//! For codebases using the `get_` naming convention, a prefix for the names of
+//! the getters can be specified using `#[enum_fields(getter_prefix = "...")]`
+//! on the enum. It applies to both the getter and the mutable getter, e.g.
+//! `get_name()` and `get_name_mut()`.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(getter_prefix = "get_")]
+//! pub enum Entity {
+//!     ...
+//! }
+//!
+//! assert_eq!(company.get_name(), "Apple");
+//! *company.get_name_mut() = "Microsoft".into();
+//! ```
+//!
+//! ### Mutable Getter Suffix
+//! The suffix of the mutable getters, `_mut`, can be changed using
+//! `#[enum_fields(mut_suffix = "...")]` on the enum, for example when it
+//! clashes with a method that is already defined.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(mut_suffix = "_mut_ref")]
+//! pub enum Entity {
+//!     ...
+//! }
+//!
+//! *company.name_mut_ref() = "Microsoft".into();
+//! ```
+//!
+//! ### Getter Suffix
+//! Similarly, a suffix for the names of the getters can be specified using
+//! `#[enum_fields(getter_suffix = "...")]` on the enum, for codebases that
+//! reserve the bare names for methods returning owned values. Unlike the
+//! prefix, it doesn't apply to the mutable getters, which keep their own
+//! suffix, so it can't be the same as that suffix.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(getter_suffix = "_ref")]
+//! pub enum Entity {
+//!     ...
+//! }
+//!
+//! assert_eq!(company.name_ref(), "Apple");
+//! *company.name_mut() = "Microsoft".into();
+//! ```
+//!
+//! ### Cloning Getters
+//! Fields annotated with `#[enum_fields(clone)]` get an additional getter
+//! returning a clone of the field, e.g. `name_cloned()` returning a `String`,
+//! for callers that can't hold on to a borrow of the enum. This getter requires
+//! the type of the field to be `Clone`.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Entity {
+//!     Company {
+//!         #[enum_fields(clone)]
+//!         name: String,
+//!     },
+//!     Person {
+//!         name: String,
+//!     },
+//! }
+//!
+//! let name: String = company.name_cloned();
+//! ```
+//!
+//! ### Flattening Options
+//! For a field of type `Option<T>` that isn't present in every variant, the
+//! getter would return an `Option<&Option<T>>`. Annotating the field with
+//! `#[enum_fields(flatten_option)]` makes the getter return an `Option<&T>`
+//! instead, which is `None` both for variants without the field and for
+//! variants where the field is `None`.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Entity {
+//!     Company {
+//!         #[enum_fields(flatten_option)]
+//!         website: Option<String>,
+//!     },
+//!     Person,
+//! }
+//! ```
+//!
+//! Annotating the enum with `#[enum_fields(flatten_option)]` does the same for
+//! every `Option<T>` field that isn't present in every variant. Note that this
+//! loses the distinction between a variant without the field and a variant
+//! where the field is `None`, which can still be made using the variant
+//! predicates.
+//!
+//! Similarly, annotating an `Option<T>` field with
+//! `#[enum_fields(option_mut_inner)]` makes the mutable getter return an
+//! `Option<&mut T>` instead of an `&mut Option<T>`, so the inner value can be
+//! modified in place. It is `None` both for variants without the field and
+//! for variants where the field is `None`.
+//!
+//! ```rs
+//! if let Some(name) = company.name_mut() {
+//!     name.push_str(" Inc.");
+//! }
+//! ```
+//!
+//! ### Defaults
+//! For a field that isn't present in every variant, a default can be specified
+//! using `#[enum_fields(default = "...")]`. The getter then returns the field
+//! directly, and a reference to the default for variants without the field,
+//! instead of returning an `Option`.
+//!
+//! The default is used as a constant, so it must be a constant expression, and
+//! the type of the field must be concrete and not need to be dropped, so a
+//! reference to it can live for `'static`. This works well for numbers and
+//! string slices, but not for a `String`, nor for types using the generic
+//! parameters or lifetimes of the enum.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Job {
+//!     Local { command: String },
+//!     Remote {
+//!         command: String,
+//!         #[enum_fields(default = "0")]
+//!         retries: u32,
+//!     },
+//! }
+//!
+//! assert_eq!(local.retries(), &0);
+//! ```
+//!
+//! For types that can't be used as a constant, such as a `String`, the field
+//! can be annotated with `#[enum_fields(or_default)]` instead. The getter then
+//! returns a clone of the field, and `T::default()` for variants without it,
+//! which requires the type to implement both `Default` and `Clone`.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Job {
+//!     Local { command: String },
+//!     Remote {
+//!         command: String,
+//!         #[enum_fields(or_default)]
+//!         host: String,
+//!     },
+//! }
+//!
+//! assert_eq!(local.host(), "");
+//! ```
+//!
+//! ### Smart Pointers
+//! For fields of type `Box<T>`, `Rc<T>`, `Arc<T>` or `Cow<'_, T>`, annotating
+//! the field with `#[enum_fields(deref)]` makes the getter return a `&T`
+//! instead of a reference to the pointer, e.g. a `&str` for a
+//! `Cow<'static, str>`. Since only a `Box` can be mutably dereferenced, the
+//! mutable getter returns a `&mut T` for a `Box<T>`, and a reference to the
+//! pointer otherwise.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Ast {
+//!     Binary {
+//!         #[enum_fields(deref)]
+//!         left: Box<Ast>,
+//!         operator: char,
+//!     },
+//!     Literal(i64),
+//! }
+//!
+//! let left: Option<&Ast> = ast.left();
+//! ```
+//!
+//! ### Getter Types
+//! The type returned by the getters of a field can be overridden using
+//! `#[enum_fields(ty = "...")]` on the field, to return a reference to a type
+//! the field coerces to, such as a `&[u8]` for a `Vec<u8>`. The mutable
+//! getter returns a `&mut` reference to that type as well. Whether the field
+//! actually coerces to the type is left to the compiler.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Packet {
+//!     Data {
+//!         #[enum_fields(ty = "[u8]")]
+//!         payload: Vec<u8>,
+//!     },
+//!     Control {
+//!         #[enum_fields(ty = "[u8]")]
+//!         payload: Vec<u8>,
+//!         code: u8,
+//!     },
+//! }
+//!
+//! let payload: &[u8] = packet.payload();
+//! ```
+//!
+//! ### Pinned Fields
+//! Annotating a field with `#[enum_fields(pin)]` makes its mutable getter take
+//! a `Pin<&mut Self>` and return a `Pin<&mut T>`, projecting a pinned enum
+//! onto the field, e.g. to poll a future stored inside it. Since this is only
+//! sound when the field is structurally pinned, the mutable getter is
+//! `unsafe`, and the caller must guarantee that:
+//! - the field is never moved out of the enum while it is pinned, e.g. by a
+//!   setter, a builder method or a `Drop` implementation;
+//! - the enum is only `Unpin` when the field is;
+//! - the enum isn't `#[repr(packed)]`.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Task {
+//!     Running {
+//!         #[enum_fields(pin)]
+//!         future: MyFuture,
+//!     },
+//!     Done,
+//! }
+//!
+//! let future: Option<Pin<&mut MyFuture>> = unsafe { task.as_mut().future_mut() };
+//! ```
+//!
+//! ### Raw Pointers
+//! For use with FFI, annotating a field with `#[enum_fields(raw_ptr)]`
+//! generates getters returning raw pointers to the field, e.g. `handle_ptr()`
+//! returning a `*const T` and `handle_ptr_mut()` returning a `*mut T`. For
+//! variants without the field, these return a null pointer instead of an
+//! `Option`, so it must be checked using `is_null()` before the
+//! pointer is used. Like any raw pointer, it is only valid as long as the enum
+//! isn't moved or dropped, and isn't accessed through a reference in between.
+//! Like the mutable getter, `handle_ptr_mut()` isn't generated for read-only
+//! fields, and returns a null pointer for variants annotated with `no_mut`.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Resource {
+//!     Open {
+//!         #[enum_fields(raw_ptr)]
+//!         handle: Handle,
+//!     },
+//!     Closed,
+//! }
+//!
+//! assert!(closed.handle_ptr().is_null());
+//! ```
+//!
+//! ### Delegating to Fields
+//! When a field is an enum deriving [`EnumFields`] as well, its getters can be
+//! made available on the outer enum using
+//! `#[enum_fields(delegate(name: Type, ...))]` on the field. Since the derive
+//! can't see the fields of the inner type, the getters to delegate must be
+//! listed along with their return types, and the inner type must have methods
+//! with those names and types. For variants without the field, the delegated
+//! getter returns `None`, wrapping the return type in an `Option`.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Event {
+//!     Connected {
+//!         #[enum_fields(delegate(address: &String, port: Option<&u16>))]
+//!         origin: Origin,
+//!     },
+//!     Message {
+//!         #[enum_fields(delegate(address: &String, port: Option<&u16>))]
+//!         origin: Origin,
+//!         content: String,
+//!     },
+//! }
+//!
+//! assert_eq!(event.address(), event.origin().address());
+//! ```
+//!
+//! ### Visibility
+//! The generated methods are `pub` by default. A different visibility can be
+//! specified for all of them using `#[enum_fields(vis = "...")]` on the enum,
+//! or for the methods of a specific field by using it on that field.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(vis = "pub(crate)")]
+//! pub enum Account {
+//!     User {
+//!         name: String,
+//!         #[enum_fields(vis = "")]
+//!         password: String,
+//!     },
+//! }
+//! ```
+//!
+//! Alternatively, annotating the enum with `#[enum_fields(inherit_vis)]` makes
+//! the generated methods use the visibility of the enum itself, so a
+//! `pub(crate)` enum gets `pub(crate)` methods. An explicit `vis` still takes
+//! precedence over it.
+//!
+//! ### Returning by Value
+//! Fields annotated with `#[enum_fields(by_value)]` are returned by value
+//! instead of by reference, which is convenient for small `Copy` types. The
+//! getter copies the field out of the variant, so it requires the type of the
+//! field to be `Copy`. This bound is placed on the getter itself, so a generic
+//! enum can still be used with a type that isn't `Copy`, as long as that
+//! getter isn't called. The mutable getter still returns a `&mut T`.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! pub enum Shape {
+//!     Circle {
+//!         #[enum_fields(by_value)]
+//!         id: u64,
+//!         radius: f32,
+//!     },
+//!     Square {
+//!         #[enum_fields(by_value)]
+//!         id: u64,
+//!     },
+//! }
+//!
+//! let mut shape = Shape::Square { id: 42 };
+//! assert_eq!(shape.id(), 42);
+//!
+//! *shape.id_mut() += 1;
+//! assert_eq!(shape.id(), 43);
+//! ```
+//!
+//! ### Bounds
+//! Additional predicates can be added to the where-clause of the generated
+//! `impl` using `#[enum_fields(bound = "...")]`, e.g. when a delegated getter
+//! calls a method of a trait on a generic field. The methods are then only
+//! available for types satisfying the bounds.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(bound = "S: Shape")]
+//! pub enum Layer<S> {
+//!     Fill {
+//!         #[enum_fields(delegate(area: f64))]
+//!         shape: S,
+//!     },
+//! }
+//! ```
+//!
+//! ### Constant Getters
+//! Annotating the enum with `#[enum_fields(const_getters)]` makes the getters
+//! `const fn`s, so they can be used in constant expressions. The mutable
+//! getters and other methods aren't affected. This requires the getters to be
+//! valid in a `const fn`, which isn't the case for `#[enum_fields(deref)]`.
+//!
+//! ```rs
+//! const ID: u64 = *Shape::Square { id: 42 }.id();
+//! ```
+//!
+//! ### Inlining
+//! The generated methods are marked `#[inline]`, so they can be inlined across
+//! crates. Annotating the enum with `#[enum_fields(inline(always))]` marks them
+//! `#[inline(always)]` instead.
+//!
+//! Like the ones of the standard derives, the generated trait implementations,
+//! such as those of `AsRef` and the companion trait, are marked
+//! `#[automatically_derived]`, so tools such as coverage reports can tell them
+//! apart from handwritten code. The attribute can't be used on inherent `impl`
+//! blocks, so those aren't marked.
+//!
+//! ### Must Use
+//! Annotating the enum with `#[enum_fields(must_use)]` marks the getters and
+//! other immutable accessors `#[must_use]`, so calling one without using its
+//! result warns. The mutable getters, setters and builder methods aren't
+//! marked, as they're meant to modify the enum.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(must_use)]
+//! pub enum Entity {
+//!     ...
+//! }
+//!
+//! // warning: unused return value of `Entity::name` that must be used
+//! entity.name();
+//! ```
+//!
+//! ### Allowing Lints
+//! Lints can be allowed on every generated method by annotating the enum with
+//! `#[enum_fields(allow = "...")]`, containing a comma-separated list of lints.
+//! This silences them for the generated methods only, instead of for the whole
+//! enum.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(allow = "clippy::must_use_candidate, clippy::wrong_self_convention")]
+//! pub enum Entity {
+//!     ...
+//! }
+//! ```
+//!
+//! ### Hiding From Documentation
+//! Annotating the enum with `#[enum_fields(doc_hidden)]` marks every generated
+//! method `#[doc(hidden)]`, so they don't clutter the documentation of internal
+//! enums, while they can still be used from other modules.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(doc_hidden)]
+//! pub enum Entity {
+//!     ...
+//! }
+//! ```
+//!
+//! ### Shared Keys
+//! Annotating the enum with `#[enum_fields(shared_key)]` generates a
+//! `shared_key()` method returning references to all fields that are present
+//! in every variant as a tuple, in the order in which they first appear. This
+//! can be used as a composite key for hashing or indexing. A single field is
+//! returned directly, and an enum without such fields can't use it.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(shared_key)]
+//! pub enum Record {
+//!     User { tenant: u32, id: u64, name: String },
+//!     Group { tenant: u32, id: u64 },
+//! }
+//!
+//! let key: (&u32, &u64) = record.shared_key();
+//! ```
+//!
+//! ### Splitting Mutable Borrows
+//! Since every mutable getter borrows the whole enum, `name_mut()` and
+//! `id_mut()` can't be used at the same time. Annotating the enum with
+//! `#[enum_fields(split_mut)]` generates a `fields_mut()` method, which returns
+//! mutable references to all fields present in every variant at once, in
+//! declaration order. These are the only fields that are guaranteed to occur
+//! together, so other fields aren't included, and neither are read-only
+//! fields.
+//!
+//! ```rs
+//! let (name, id): (&mut String, &mut u32) = company.fields_mut();
+//! name.push_str(" Inc.");
+//! *id += 1;
+//! ```
+//!
+//! ### `AsRef` Implementations
+//! Annotating the enum with `#[enum_fields(as_ref)]` implements [`AsRef`] for
+//! the field that is present in every variant, e.g. `AsRef<String>` for the
+//! `name` of an `Entity`. When several fields are present in every variant,
+//! this is ambiguous and results in a compile error. The field to use can then
+//! be picked by annotating it with `#[enum_fields(as_ref)]` instead.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(as_ref)]
+//! pub enum Entity {
+//!     ...
+//! }
+//!
+//! fn print(text: impl AsRef<String>) { ... }
+//!
+//! print(company);
+//! ```
+//!
+//! ### Transparent Enums
+//! Annotating the enum with `#[enum_fields(transparent)]` implements
+//! [`Deref`](std::ops::Deref) to the field of its variants, which requires
+//! every variant to hold exactly one field, with the same name and type. This
+//! suits enums that merely tag a value, e.g. where a name came from.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(transparent)]
+//! pub enum Name {
+//!     Given(String),
+//!     Nickname(String),
+//! }
+//!
+//! assert_eq!(Name::Nickname("Tim".into()).len(), 3);
+//! ```
+//!
+//! ### Unions
+//! Deriving [`EnumFields`] for a `union` generates `unsafe` getters and mutable
+//! getters for its fields, which have to be opted into by annotating the union
+//! with `#[enum_fields(unsafe_getters)]`. Since a union doesn't know which of
+//! its fields holds a value, the getters can't check it either. It is up to
+//! the caller to make sure the field holds a valid value of its type, e.g.
+//! because it is the field that was written last, like when reading the field
+//! directly.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(unsafe_getters)]
+//! pub union Bits {
+//!     integer: u32,
+//!     float: f32,
+//! }
+//!
+//! let bits = Bits { float: 1.0 };
+//! // SAFETY: `float` was written last.
+//! assert_eq!(unsafe { bits.float() }, &1.0);
+//! ```
+//!
+//! The names of the getters can be changed like those of an enum, using
+//! `getter_prefix`, `getter_suffix`, `mut_suffix` and `rename`. Options that
+//! need to know which field holds a value, like `setters` or `by_value`,
+//! can't be used on unions.
+//!
+//! ### Companion Trait
+//! Annotating the enum with `#[enum_fields(trait_name = "...")]` generates a
+//! trait with that name containing the getters, which is implemented by the
+//! enum. This allows functions to be generic over enums with the same fields.
+//! The trait is generated per enum and only contains the getters of that
+//! enum, so other types with the same fields have to implement it by hand.
+//! The trait has the visibility of the enum, so getters with a more
+//! restricted visibility, e.g. using `#[enum_fields(vis = "pub(crate)")]` on
+//! a field of a `pub` enum, are left out of it.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(trait_name = "EntityFields")]
+//! pub enum Entity {
+//!     ...
+//! }
+//!
+//! fn print_name(entity: &impl EntityFields) {
+//!     println!("{}", entity.name());
+//! }
+//! ```
+//!
+//! Recursive data structures often hold the enum in a `Box`. Method calls on a
+//! `Box<Entity>` already reach the getters through auto-deref, but a
+//! `Box<Entity>` doesn't implement the trait. Annotating the enum with
+//! `#[enum_fields(impl_for_box)]` as well implements the trait for
+//! `Box<Entity>`, forwarding to the boxed enum.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(trait_name = "EntityFields", impl_for_box)]
+//! pub enum Entity {
+//!     ...
+//! }
+//!
+//! print_name(&Box::new(company));
+//! ```
+//!
+//! With the `trait-accessors` feature of this crate enabled, every enum gets a
+//! companion trait, even without `#[enum_fields(trait_name = "...")]`. The
+//! trait is then named after the enum, e.g. `EntityFields` for `Entity`, which
+//! must therefore not be used by another item. Without the feature, no trait
+//! is generated unless requested, so there is no cost when it isn't used.
+//! Like any companion trait, it leaves out the getters with a restricted
+//! visibility, so enabling the feature doesn't expose them.
+//!
+//! ```toml
+//! enum-fields = { version = "0.1", features = ["trait-accessors"] }
+//! ```
+//!
+//! ### Wrappers
+//! A tuple struct wrapping an enum that derives [`EnumFields`] can get the
+//! getters and mutable getters of the enum as well, forwarding to the wrapped
+//! enum, by annotating it with [`enum_fields_delegate`]. It takes the name of
+//! the enum and the field containing it, which must be the only field of the
+//! struct. The wrapper must be declared after the enum, in the same module,
+//! and neither of them can be generic.
+//!
+//! ```rs
+//! #[enum_fields::enum_fields_delegate(Entity, 0)]
+//! pub struct Owner(Entity);
+//!
+//! assert_eq!(owner.name(), "Apple");
+//! assert_eq!(owner.ceo(), Some(&"Tim Cook".into()));
+//! ```
+//!
+//! ### Fallible Getters
+//! Annotating the enum with `#[enum_fields(try)]` generates getters returning a
+//! [`Result`] for fields that aren't present in every variant, e.g.
+//! `try_ceo()`. The [`EnumFieldError`] describes which variant lacked the
+//! field.
+//!
+//! ```rs
+//! assert_eq!(company.try_ceo(), Ok(&"Tim Cook".into()));
+//!
+//! let error = person.try_ceo().unwrap_err();
+//! assert_eq!(error.to_string(), "variant `Person` doesn't have the field `ceo`");
+//! ```
+//!
+//! For code using its own error type, the error of the getters can be changed
+//! using `#[enum_fields(getter_result = "MyError")]`, which requires
+//! `MyError` to implement `From<EnumFieldError>`. The getters then return a
+//! `Result<&T, MyError>`, so they can be used with the `?` operator directly.
+//!
+//! ```rs
+//! #[derive(enum_fields::EnumFields)]
+//! #[enum_fields(try, getter_result = "LookupError")]
+//! pub enum Entity {
+//!     ...
+//! }
+//!
+//! let ceo: Result<&String, LookupError> = person.try_ceo();
+//! ```
 
-use std::collections::HashMap;
-
-use proc_macro::TokenStream;
-use proc_macro2::{Ident, Span};
-use quote::quote;
-use syn;
-
-#[proc_macro_derive(EnumFields)]
-pub fn enum_fields_macro_derive(input: TokenStream) -> TokenStream {
-    let ast = syn::parse(input).unwrap();
-    impl_for_input(&ast)
-}
-
-fn collect_available_fields(enum_data: &syn::DataEnum) -> HashMap<String, Vec<&syn::Field>> {
-    let mut fields = HashMap::new();
-
-    for variant in &enum_data.variants {
-        for field in &variant.fields {
-            if let Some(field_ident) = &field.ident {
-                let ident = field_ident.to_string();
-                fields.entry(ident)
-                    .or_insert(Vec::new())
-                    .push(field);
-            }
-        }
-    }
-
-    fields
-}
-
-fn impl_for_input(ast: &syn::DeriveInput) -> TokenStream {
-    let fail_message = "`EnumFields` is only applicable to `enum`s";
-    match &ast.data {
-        syn::Data::Enum(data_enum) => impl_for_enum(ast, &data_enum),
-        syn::Data::Union(data_union) => syn::Error::new(data_union.union_token.span, fail_message).to_compile_error().into(),
-        syn::Data::Struct(data_struct) => syn::Error::new(data_struct.struct_token.span, fail_message).to_compile_error().into(),
-    }
-}
-
-fn impl_for_enum(ast: &syn::DeriveInput, enum_data: &syn::DataEnum) -> TokenStream {
-    let name = &ast.ident;
-
-    // Collect available fields
-    let fields = collect_available_fields(enum_data);
-
-    let mut data = proc_macro2::TokenStream::new();
-
-
-    for (field_name, fields) in fields {
-        let field_present_everywhere = fields.len() == enum_data.variants.len();
-
-        let generics = &ast.generics;
-        let field_type = &fields[0].ty;
-        let field_name_ident = Ident::new(&field_name, Span::call_site());
-        let field_name_ident_mut = Ident::new(&format!("{field_name}_mut"), Span::call_site());
-
-        let mut variants = proc_macro2::TokenStream::new();
-
-        for variant in &enum_data.variants {
-            let name = &variant.ident;
-
-            let variant_field_ident = variant.fields.iter()
-                .find(|variant_field| {
-                    if let Some(variant_field_ident) = &variant_field.ident {
-                        if variant_field_ident.to_string() == field_name {
-                            true
-                        } else {
-                            false
-                        }
-                    } else {
-                        false
-                    }
-                })
-                .map(|field| {
-                    field.ident.as_ref().unwrap()
-                });
-
-            match variant_field_ident {
-                Some(variant_field_ident) => {
-                    if field_present_everywhere {
-                        variants.extend(quote! {
-                            Self::#name{ #variant_field_ident, .. } => #variant_field_ident,
-                        });
-                    } else {
-                        variants.extend(quote! {
-                            Self::#name{ #variant_field_ident, .. } => Some(#variant_field_ident),
-                        });
-                    }
-                }
-
-                None => {
-                    // Field not present in field list.
-                    if let Some(first_field) = variant.fields.iter().next() {
-                        if first_field.ident.is_some() {
-                            variants.extend(quote! {
-                                Self::#name{ .. } => None,
-                            });
-                        } else {
-                            variants.extend(quote! {
-                                Self::#name(..) => None,
-                            });
-                        }
-                    } else {
-                        variants.extend(quote! {
-                            Self::#name => None,
-                        });
-                    }
-                }
-            }
-        }
-
-        let ty = if field_present_everywhere {
-            quote! {
-                & #field_type
-            }
-        } else {
-            quote! {
-                Option<& #field_type>
-            }
-        };
-
-        let ty_mut = if field_present_everywhere {
-            quote! {
-                &mut #field_type
-            }
-        } else {
-            quote! {
-                Option<&mut #field_type>
-            }
-        };
-
-        data.extend(quote! {
-            impl #generics #name #generics {
-                pub fn #field_name_ident(&self) -> #ty {
-                    //! Get the property of this enum discriminant if it's available
-                    match self {
-                        #variants
-                    }
-                }
+mod error;
 
-                 pub fn #field_name_ident_mut(&mut self) -> #ty_mut {
-                    //! Get the mutable property of this enum discriminant if it's available
-                    match self {
-                        #variants
-                    }
-                }
-            }
-        });
-    }
+pub use enum_fields_derive::{enum_fields_delegate, EnumFields};
 
-    data.into()
-}
+pub use crate::error::EnumFieldError;
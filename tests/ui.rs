@@ -0,0 +1,17 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! Tests of the compile errors of [`enum_fields::EnumFields`], of which the
+//! expected output is stored next to each case in `tests/ui`.
+//!
+//! Run with `TRYBUILD=overwrite` to update the expected output after changing
+//! an error message.
+
+// The companion traits of the `trait-accessors` feature produce additional
+// errors, so the expected output only holds without it.
+#[cfg(not(feature = "trait-accessors"))]
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,16 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+// Allowing a forbidden lint is an error, which shows the `#[allow(...)]` is
+// forwarded to the generated methods.
+#![forbid(unreachable_pub)]
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(allow = "unreachable_pub")]
+pub enum Shape {
+    Circle {
+        radius: u32,
+    },
+}
+
+fn main() {}
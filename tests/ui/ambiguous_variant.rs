@@ -0,0 +1,16 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+pub enum Version {
+    Major {
+        major: u32,
+    },
+
+    Minor {
+        major: u32,
+        minor: u32,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(as_ref)]
+pub enum Document {
+    Plain {
+        title: String,
+        body: String,
+    },
+
+    Markdown {
+        title: String,
+        body: String,
+    },
+}
+
+#[derive(enum_fields::EnumFields)]
+pub enum Entity {
+    Company {
+        name: String,
+
+        #[enum_fields(as_ref)]
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(bound = "T: std::fmt::Display")]
+pub enum Label<T> {
+    Text {
+        value: T,
+    },
+}
+
+struct Opaque;
+
+fn main() {
+    let label = Label::Text { value: Opaque };
+    let _ = label.value();
+}
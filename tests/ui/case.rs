@@ -0,0 +1,11 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(case = "kebab")]
+pub enum Status {
+    Ok,
+    NotFound,
+}
+
+fn main() {}
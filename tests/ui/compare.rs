@@ -0,0 +1,22 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(compare, eq_field = "callback")]
+pub enum Button {
+    Text {
+        label: String,
+        callback: Box<dyn Fn()>,
+    },
+}
+
+fn main() {
+    let button = Button::Text {
+        label: "OK".into(),
+        callback: Box::new(|| println!("Clicked")),
+    };
+
+    button.same_label(&button);
+    button.same_callback(&button);
+    button.eq_by_callback(&button);
+}
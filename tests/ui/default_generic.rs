@@ -0,0 +1,32 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+pub enum Job<'a, T> {
+    Local {
+        command: &'a str,
+    },
+
+    Remote {
+        command: &'a str,
+
+        #[enum_fields(default = "0")]
+        retries: T,
+    },
+}
+
+#[derive(enum_fields::EnumFields)]
+pub enum Task<'a> {
+    Local {
+        id: u32,
+    },
+
+    Remote {
+        id: u32,
+
+        #[enum_fields(default = "\"localhost\"")]
+        host: &'a str,
+    },
+}
+
+fn main() {}
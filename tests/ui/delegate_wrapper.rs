@@ -0,0 +1,19 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+pub enum Entity {
+    Company {
+        name: String,
+    },
+}
+
+#[enum_fields::enum_fields_delegate(Entity, 0)]
+pub struct Owner {
+    entity: Entity,
+}
+
+#[enum_fields::enum_fields_delegate(Entity, 1)]
+pub struct Holder(Entity);
+
+fn main() {}
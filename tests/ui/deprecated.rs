@@ -0,0 +1,20 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#![deny(deprecated)]
+
+#[derive(enum_fields::EnumFields)]
+pub enum Setting {
+    Flag {
+        #[deprecated = "use `path` instead"]
+        key: String,
+        path: String,
+    },
+}
+
+fn main() {
+    #[allow(deprecated)]
+    let setting = Setting::Flag { key: "wrap".into(), path: "editor.wrap".into() };
+    let _ = setting.path();
+    let _ = setting.key();
+}
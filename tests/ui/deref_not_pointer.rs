@@ -0,0 +1,12 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+pub enum Ast {
+    Negate {
+        #[enum_fields(deref)]
+        operand: Vec<Ast>,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(eq_field = "ceo")]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(eq_field = "id")]
+pub enum Robot {
+    Droid {
+        name: String,
+    },
+}
+
+fn main() {}
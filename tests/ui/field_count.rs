@@ -0,0 +1,13 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+pub enum Table {
+    Sparse {
+        field_count: usize,
+    },
+
+    Dense(Vec<u8>),
+}
+
+fn main() {}
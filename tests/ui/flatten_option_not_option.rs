@@ -0,0 +1,14 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+pub enum Entity {
+    Company {
+        #[enum_fields(flatten_option)]
+        name: String,
+    },
+
+    Person,
+}
+
+fn main() {}
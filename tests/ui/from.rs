@@ -0,0 +1,19 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+pub enum Entity {
+    Company {
+        name: String,
+
+        #[enum_fields(from)]
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+        age: u8,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(getter_prefix = "get-")]
+pub enum Entity {
+    Company {
+        name: String,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+pub struct LookupError;
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(getter_result = "LookupError")]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(try, getter_result = "LookupError")]
+pub enum Robot {
+    Droid {
+        name: String,
+        owner: String,
+    },
+
+    Drone {
+        name: String,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(getter_suffix = "_mut")]
+pub enum Entity {
+    Company {
+        name: String,
+    },
+}
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(getter_suffix = "_ref", mut_suffix = "_ref")]
+pub enum Robot {
+    Droid {
+        name: String,
+    },
+}
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(getter_suffix = "-ref")]
+pub enum Animal {
+    Dog {
+        name: String,
+    },
+}
+
+fn main() {}
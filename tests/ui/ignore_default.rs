@@ -0,0 +1,13 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+pub enum Message {
+    Chat { id: u32, text: String },
+    Leave { id: u32 },
+
+    #[enum_fields(ignore)]
+    Unknown(String),
+}
+
+fn main() {}
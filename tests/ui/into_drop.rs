@@ -0,0 +1,16 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(into)]
+pub enum Connection {
+    Open {
+        address: String,
+    },
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {}
+}
+
+fn main() {}
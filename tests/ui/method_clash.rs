@@ -0,0 +1,23 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+pub enum Entity {
+    Company {
+        variant_name: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+#[derive(enum_fields::EnumFields)]
+#[allow(non_snake_case)]
+pub enum Color {
+    Rgb {
+        VARIANTS: u8,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+pub enum Entity {
+    Company {
+        name: String,
+    },
+
+    Person {
+        name: &'static str,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#![deny(unused_must_use)]
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(must_use, setters)]
+pub enum Account {
+    User {
+        name: String,
+    },
+
+    Admin {
+        name: String,
+        level: u8,
+    },
+}
+
+fn main() {
+    let mut account = Account::User { name: "root".into() };
+
+    // The mutable accessors aren't `#[must_use]`.
+    account.name_mut();
+    account.set_level(3);
+
+    account.name();
+    account.level();
+    account.is_admin();
+}
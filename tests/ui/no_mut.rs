@@ -0,0 +1,18 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::rc::Rc;
+
+#[derive(enum_fields::EnumFields)]
+pub enum Node {
+    Leaf {
+        #[enum_fields(no_mut)]
+        shared: Rc<str>,
+    },
+}
+
+fn main() {
+    let mut leaf = Node::Leaf { shared: "leaf".into() };
+    let _ = leaf.shared();
+    let _ = leaf.shared_mut();
+}
@@ -0,0 +1,25 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(only(name, identifier))]
+pub enum Entity {
+    Company {
+        id: u32,
+        name: String,
+    },
+}
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(only(name))]
+pub enum Robot {
+    Droid {
+        id: u32,
+        name: String,
+    },
+}
+
+fn main() {
+    let robot = Robot::Droid { id: 1, name: "R2-D2".into() };
+    let _ = robot.id();
+}
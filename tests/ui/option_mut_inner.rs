@@ -0,0 +1,12 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+pub enum Entity {
+    Company {
+        #[enum_fields(option_mut_inner)]
+        name: String,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+pub enum Job {
+    Local,
+    Remote {
+        #[enum_fields(or_default, default = "0")]
+        retries: u32,
+    },
+}
+
+pub struct Host;
+
+#[derive(enum_fields::EnumFields)]
+pub enum Connection {
+    Local,
+    Remote {
+        #[enum_fields(or_default)]
+        host: Host,
+    },
+}
+
+fn main() {}
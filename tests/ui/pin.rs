@@ -0,0 +1,23 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+pub enum Task {
+    Running {
+        #[enum_fields(pin, deref)]
+        counter: Box<u32>,
+    },
+}
+
+#[derive(enum_fields::EnumFields)]
+pub enum Job {
+    Running {
+        #[enum_fields(pin)]
+        counter: u32,
+    },
+}
+
+fn main() {
+    let mut job = Box::pin(Job::Running { counter: 0 });
+    let _ = job.as_mut().counter_mut();
+}
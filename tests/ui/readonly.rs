@@ -0,0 +1,19 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(readonly)]
+pub enum Entity {
+    Company {
+        name: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+fn main() {
+    let mut company = Entity::Company { name: "Apple".into() };
+    company.name_mut().push_str(" Inc.");
+}
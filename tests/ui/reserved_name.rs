@@ -0,0 +1,16 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(Clone, enum_fields::EnumFields)]
+pub enum Sheep {
+    Original {
+        name: String,
+    },
+
+    Copy {
+        name: String,
+        clone: bool,
+    },
+}
+
+fn main() {}
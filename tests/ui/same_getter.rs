@@ -0,0 +1,16 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+pub enum Entity {
+    Company {
+        name: String,
+    },
+
+    Person {
+        #[enum_fields(rename = "name")]
+        full_name: String,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(shared_key)]
+pub enum Shape {
+    Circle {
+        radius: f64,
+    },
+
+    Square {
+        side: f64,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+pub struct Entity {
+    name: String,
+}
+
+fn main() {}
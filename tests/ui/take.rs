@@ -0,0 +1,21 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(take)]
+pub enum Task {
+    Once {
+        label: String,
+        callback: Box<dyn Fn()>,
+    },
+}
+
+fn main() {
+    let mut task = Task::Once {
+        label: "print".into(),
+        callback: Box::new(|| println!("Hello")),
+    };
+
+    task.take_label();
+    task.take_callback();
+}
@@ -0,0 +1,27 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+mod entity {
+    #[derive(enum_fields::EnumFields)]
+    #[enum_fields(trait_name = "EntityFields")]
+    pub enum Entity {
+        Company {
+            name: String,
+
+            #[enum_fields(vis = "pub(self)")]
+            secret: String,
+        },
+    }
+}
+
+use entity::EntityFields;
+
+fn name(entity: &impl EntityFields) -> &String {
+    entity.name()
+}
+
+fn secret(entity: &impl EntityFields) -> &String {
+    entity.secret()
+}
+
+fn main() {}
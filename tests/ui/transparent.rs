@@ -0,0 +1,24 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(transparent)]
+pub enum Entity {
+    Company {
+        name: String,
+        ceo: String,
+    },
+
+    Person {
+        name: String,
+    },
+}
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(transparent)]
+pub enum Name {
+    Given(String),
+    Number(u32),
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(transparent)]
+pub enum Name {
+    Full(String),
+
+    #[enum_fields(ignore)]
+    Unknown(String),
+}
+
+fn main() {}
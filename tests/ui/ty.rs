@@ -0,0 +1,20 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+pub enum Packet {
+    Data {
+        #[enum_fields(ty = "[u8]", deref)]
+        payload: Box<Vec<u8>>,
+    },
+}
+
+#[derive(enum_fields::EnumFields)]
+pub enum Message {
+    Text {
+        #[enum_fields(ty = "[u8]")]
+        body: String,
+    },
+}
+
+fn main() {}
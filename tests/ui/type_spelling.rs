@@ -0,0 +1,32 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+pub type Name = String;
+
+mod text {
+    pub type String = Vec<char>;
+}
+
+#[derive(enum_fields::EnumFields)]
+pub enum Entity {
+    Company {
+        name: String,
+    },
+
+    Person {
+        name: Name,
+    },
+}
+
+#[derive(enum_fields::EnumFields)]
+pub enum Document {
+    Draft {
+        title: String,
+    },
+
+    Published {
+        title: crate::text::String,
+    },
+}
+
+fn main() {}
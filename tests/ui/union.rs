@@ -0,0 +1,10 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+pub union Bits {
+    integer: u32,
+    float: f32,
+}
+
+fn main() {}
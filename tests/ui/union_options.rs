@@ -0,0 +1,37 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(unsafe_getters, setters)]
+pub union Setters {
+    integer: u32,
+}
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(unsafe_getters)]
+pub union ByValue {
+    #[enum_fields(by_value)]
+    integer: u32,
+}
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(unsafe_getters)]
+pub union Deref {
+    #[enum_fields(deref)]
+    integer: std::mem::ManuallyDrop<Box<u32>>,
+}
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(unsafe_getters)]
+pub union Reserved {
+    clone: u32,
+}
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(unsafe_getters, mut_suffix = "_bits")]
+pub union Clash {
+    integer: u32,
+    integer_bits: u32,
+}
+
+fn main() {}
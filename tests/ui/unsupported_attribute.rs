@@ -0,0 +1,13 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+#[derive(enum_fields::EnumFields)]
+#[enum_fields(getters)]
+pub enum Entity {
+    Company {
+        #[enum_fields(hidden)]
+        name: String,
+    },
+}
+
+fn main() {}